@@ -0,0 +1,131 @@
+/// With the `embed_inputs` feature, generates the code that embeds the inputs and examples
+/// and the list of solutions for the `bundle` binary. Without it, nothing is embedded.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let (embedded, bundle) = if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        println!("cargo:rerun-if-changed=aoc.toml");
+        println!("cargo:rerun-if-changed=src/bin");
+        for var in ["AOC_PATHS_DATA", "AOC_PATHS_INPUTS", "AOC_PATHS_EXAMPLES"] {
+            println!("cargo:rerun-if-env-changed={var}");
+        }
+
+        let data = root.join(path_setting(&root, "data", "data"));
+        let mut files = vec![];
+        for folder in ["inputs", "examples"] {
+            let dir = data.join(path_setting(&root, folder, folder));
+            println!("cargo:rerun-if-changed={}", dir.display());
+            files.extend(text_files(&dir).into_iter().map(|path| (folder, path)));
+        }
+
+        (
+            embed_files(&files),
+            bundle_days(&solutions(&root.join("src").join("bin"))),
+        )
+    } else {
+        (embed_files(&[]), bundle_days(&[]))
+    };
+
+    fs::write(out_dir.join("embedded.rs"), embedded).unwrap();
+    fs::write(out_dir.join("bundle.rs"), bundle).unwrap();
+}
+
+/// Reads a folder of the `[paths]` section of `aoc.toml` with the same parser as `template::config`.
+/// An invalid config is reported by the commands at runtime, so the defaults are used here.
+fn path_setting(root: &Path, key: &str, default: &str) -> String {
+    if let Ok(value) = env::var(format!("AOC_PATHS_{}", key.to_uppercase())) {
+        return value;
+    }
+
+    let config = fs::read_to_string(root.join("aoc.toml")).unwrap_or_default();
+
+    config
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|table| match table.get("paths")?.get(key)? {
+            toml::Value::String(value) => Some(value.clone()),
+            _ => None,
+        })
+        .unwrap_or_else(|| default.to_string())
+}
+
+fn text_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files: Vec<PathBuf> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+
+    files.sort();
+    files
+}
+
+fn embed_files(files: &[(&str, PathBuf)]) -> String {
+    let mut code = String::from(
+        "/// The embedded text of a file in the `inputs` or `examples` folder, e.g. `(\"inputs\", \"01.txt\")`.\n",
+    );
+
+    if files.is_empty() {
+        code.push_str("pub fn file(_: &str, _: &str) -> Option<&'static str> {\n    None\n}\n");
+        return code;
+    }
+
+    code.push_str(
+        "pub fn file(folder: &str, file_name: &str) -> Option<&'static str> {\n    match (folder, file_name) {\n",
+    );
+
+    for (folder, path) in files {
+        let file_name = path.file_name().unwrap().to_string_lossy();
+        code.push_str(&format!(
+            "        ({folder:?}, {file_name:?}) => Some(include_str!({:?})),\n",
+            path.display().to_string()
+        ));
+    }
+
+    code.push_str("        _ => None,\n    }\n}\n");
+    code
+}
+
+/// The solutions in `src/bin` by day.
+fn solutions(bin: &Path) -> Vec<(u8, PathBuf)> {
+    let mut days: Vec<(u8, PathBuf)> = fs::read_dir(bin)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let stem = path.file_stem()?.to_str()?;
+            let is_solution = path.extension()? == "rs" && stem.len() == 2;
+            Some((stem.parse().ok().filter(|_| is_solution)?, path))
+        })
+        .collect();
+
+    days.sort();
+    days
+}
+
+/// Includes every solution as a module of the `bundle` binary.
+fn bundle_days(days: &[(u8, PathBuf)]) -> String {
+    let mut code = String::new();
+    for (day, path) in days {
+        code.push_str(&format!(
+            "mod day{day:02} {{\n    pub fn run() {{\n        main();\n    }}\n\n    include!({:?});\n}}\n\n",
+            path.display().to_string()
+        ));
+    }
+
+    code.push_str("/// Every bundled solution, in order.\nconst DAYS: &[(u8, fn())] = &[\n");
+    for (day, _) in days {
+        code.push_str(&format!("    ({day}, day{day:02}::run),\n"));
+    }
+    code.push_str("];\n");
+    code
+}
@@ -0,0 +1,169 @@
+use std::convert::Infallible;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The answer to a puzzle part.
+///
+/// Integers are normalized on construction: non-negative values are always stored
+/// as [`Answer::Unsigned`] and only negative values as [`Answer::Signed`], so answers
+/// created from different integer types compare equal if they have the same value.
+///
+/// ```
+/// # use advent_of_code::Answer;
+/// assert_eq!(Answer::from(42_u8), Answer::from(42_i64));
+/// assert_eq!(Answer::from(-1_i32).to_string(), "-1");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+    /// Multi-line ASCII art, e.g. rendered block letters.
+    Art(String),
+}
+
+impl Answer {
+    /// Returns `true` if the answer spans multiple lines.
+    #[must_use]
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Text(s) | Answer::Art(s) if s.contains('\n'))
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Text(s) | Answer::Art(s) => f.write_str(s),
+        }
+    }
+}
+
+macro_rules! impl_from_unsigned {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::Unsigned(value as u128)
+            }
+        })*
+    };
+}
+
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            #[allow(clippy::cast_sign_loss)]
+            fn from(value: $t) -> Self {
+                if value < 0 {
+                    Answer::Signed(value as i128)
+                } else {
+                    Answer::Unsigned(value as u128)
+                }
+            }
+        })*
+    };
+}
+
+impl_from_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_from_signed!(i8, i16, i32, i64, i128, isize);
+
+/// Text that spells an integer in its canonical form, e.g. `"42"` but not `"042"` or `"+42"`,
+/// is stored as that integer, so it equals the answer parsed from a stored file.
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        if let Some(answer) = parse_integer(&value) {
+            answer
+        } else if value.contains('\n') {
+            Answer::Art(value)
+        } else {
+            Answer::Text(value)
+        }
+    }
+}
+
+fn parse_integer(s: &str) -> Option<Answer> {
+    let answer = match s.parse::<u128>() {
+        Ok(n) => Answer::Unsigned(n),
+        Err(_) => Answer::from(s.parse::<i128>().ok()?),
+    };
+    (answer.to_string() == s).then_some(answer)
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::from(value.to_string())
+    }
+}
+
+/// Digits are stored as integers, like [`Answer::from`] a `String`.
+impl From<char> for Answer {
+    fn from(value: char) -> Self {
+        Answer::from(value.to_string())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Parses the [`Display`] representation of an answer, the same way as
+    /// [`Answer::from`] a string: integers are recognized, everything else becomes
+    /// [`Answer::Text`] or [`Answer::Art`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Answer::from(s))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn normalizes_integers() {
+        assert_eq!(Answer::from(7_u32), Answer::Unsigned(7));
+        assert_eq!(Answer::from(7_i64), Answer::Unsigned(7));
+        assert_eq!(Answer::from(-7_i8), Answer::Signed(-7));
+        assert_eq!(Answer::from(u128::MAX), Answer::Unsigned(u128::MAX));
+        assert_eq!(Answer::from(i128::MIN), Answer::Signed(i128::MIN));
+    }
+
+    #[test]
+    fn normalizes_numeric_text() {
+        assert_eq!(Answer::from("123"), Answer::Unsigned(123));
+        assert_eq!(Answer::from("-5".to_string()), Answer::Signed(-5));
+        assert_eq!(Answer::from("0123"), Answer::Text("0123".into()));
+        assert_eq!(Answer::from("+1"), Answer::Text("+1".into()));
+        assert_eq!("0123".parse::<Answer>(), Ok(Answer::from("0123")));
+    }
+
+    #[test]
+    fn normalizes_chars() {
+        assert_eq!(Answer::from('5'), Answer::Unsigned(5));
+        assert_eq!(Answer::from('5'), "5".parse().unwrap());
+        assert_eq!(Answer::from('x'), Answer::Text("x".into()));
+    }
+
+    #[test]
+    fn detects_art() {
+        assert_eq!(Answer::from("abc"), Answer::Text("abc".into()));
+        assert!(Answer::from("#.\n.#".to_string()).is_multiline());
+    }
+
+    #[test]
+    fn roundtrips_through_strings() {
+        for answer in [
+            Answer::from(0_u8),
+            Answer::from(u128::MAX),
+            Answer::from(-12_i64),
+            Answer::from(i128::MIN),
+            Answer::from("ABCD"),
+            Answer::from("#..#\n####"),
+        ] {
+            assert_eq!(answer.to_string().parse::<Answer>().unwrap(), answer);
+        }
+    }
+}
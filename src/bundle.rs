@@ -0,0 +1,52 @@
+/// A single binary with every solution and its embedded input, built by `cargo bundle`.
+///
+/// `bundle <day>` runs one day and `bundle` or `bundle all` runs every day with an input.
+/// Other arguments are passed on to the solutions, e.g. `bundle 05 --time`.
+use std::{env, process};
+
+use advent_of_code::template::{input, ANSI_BOLD, ANSI_RESET};
+use advent_of_code::Day;
+
+include!(concat!(env!("OUT_DIR"), "/bundle.rs"));
+
+fn main() {
+    let selected = env::args().nth(1).filter(|arg| !arg.starts_with('-'));
+
+    match selected.as_deref() {
+        None | Some("all") => run_all(),
+        Some(arg) => {
+            let Some(run) = arg
+                .parse::<Day>()
+                .ok()
+                .and_then(|day| DAYS.iter().find(|(d, _)| day == *d))
+                .map(|(_, run)| run)
+            else {
+                let days: Vec<String> = DAYS.iter().map(|(day, _)| format!("{day:02}")).collect();
+                eprintln!("Unknown day `{arg}`. Bundled days: {}", days.join(", "));
+                process::exit(1);
+            };
+
+            run();
+        }
+    }
+}
+
+fn run_all() {
+    let mut is_first = true;
+
+    for (day, run) in DAYS {
+        let input = input::embedded("inputs", &format!("{day:02}.txt"));
+        if input.is_none_or(|input| input.trim().is_empty()) {
+            continue;
+        }
+
+        if !is_first {
+            println!();
+        }
+        is_first = false;
+
+        println!("{ANSI_BOLD}Day {day:02}{ANSI_RESET}");
+        println!("------");
+        run();
+    }
+}
@@ -35,6 +35,11 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Whether the puzzle has a second part. The puzzle of the last day only has one.
+    pub fn has_part_two(self) -> bool {
+        self.0 != 25
+    }
 }
 
 impl Display for Day {
@@ -114,6 +119,162 @@ impl Iterator for AllDays {
 
 /* -------------------------------------------------------------------------- */
 
+/// A set of days, e.g. to select the days a command runs for.
+///
+/// # Parsing
+/// A set is parsed from a comma-separated list of days and ranges of days.
+/// Ranges include both ends, and a range that is open at one end extends to the first or last day.
+///
+/// ```
+/// # use advent_of_code::DaySet;
+/// let days: DaySet = "1-5,8,12-".parse().unwrap();
+/// assert_eq!(days.iter().count(), 20);
+/// assert_eq!(days.to_string(), "1-5,8,12-25");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DaySet(u32);
+
+impl DaySet {
+    /// Creates a set that contains no day.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Creates a set that contains every day from the 1st to the 25th.
+    #[must_use]
+    pub fn all() -> Self {
+        all_days().collect()
+    }
+
+    #[must_use]
+    pub fn contains(&self, day: Day) -> bool {
+        self.0 & (1 << day.0) != 0
+    }
+
+    pub fn insert(&mut self, day: Day) {
+        self.0 |= 1 << day.0;
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the days that are in `self` but not in `other`.
+    #[must_use]
+    pub fn difference(&self, other: &DaySet) -> DaySet {
+        Self(self.0 & !other.0)
+    }
+
+    /// Iterates the days of the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = Day> + '_ {
+        all_days().filter(|day| self.contains(*day))
+    }
+}
+
+impl FromIterator<Day> for DaySet {
+    fn from_iter<T: IntoIterator<Item = Day>>(iter: T) -> Self {
+        let mut set = Self::empty();
+        iter.into_iter().for_each(|day| set.insert(day));
+        set
+    }
+}
+
+impl Display for DaySet {
+    /// Displays the set in its shortest form, e.g. `1-5,8,12-25`.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut ranges: Vec<(u8, u8)> = vec![];
+
+        for day in self.iter() {
+            match ranges.last_mut() {
+                Some((_, end)) if *end + 1 == day.0 => *end = day.0,
+                _ => ranges.push((day.0, day.0)),
+            }
+        }
+
+        let ranges: Vec<String> = ranges
+            .into_iter()
+            .map(|(start, end)| match end - start {
+                0 => start.to_string(),
+                // two days are shorter as a list than as a range.
+                1 => format!("{start},{end}"),
+                _ => format!("{start}-{end}"),
+            })
+            .collect();
+
+        f.write_str(&ranges.join(","))
+    }
+}
+
+impl FromStr for DaySet {
+    type Err = DaySetFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day: &str, default: u8| -> Result<Day, DaySetFromStrError> {
+            let day = day.trim();
+            if day.is_empty() {
+                return Ok(Day(default));
+            }
+            day.parse()
+                .map_err(|_| DaySetFromStrError::InvalidDay(day.to_string()))
+        };
+
+        let mut set = Self::empty();
+
+        for item in s.split(',') {
+            let item = item.trim();
+            if item.is_empty() {
+                return Err(DaySetFromStrError::Empty);
+            }
+
+            match item.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (parse_day(start, 1)?, parse_day(end, 25)?);
+                    if start > end {
+                        return Err(DaySetFromStrError::InvalidRange(item.to_string()));
+                    }
+                    (start.0..=end.0).for_each(|day| set.insert(Day(day)));
+                }
+                None => set.insert(parse_day(item, 0)?),
+            }
+        }
+
+        Ok(set)
+    }
+}
+
+/// An error which can be returned when parsing a [`DaySet`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum DaySetFromStrError {
+    /// The list or one of its items is empty.
+    Empty,
+    /// An item is not a day number between 1 and 25.
+    InvalidDay(String),
+    /// A range ends before it starts.
+    InvalidRange(String),
+}
+
+impl Error for DaySetFromStrError {}
+
+impl Display for DaySetFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DaySetFromStrError::Empty => {
+                f.write_str("expecting a comma-separated list of days, e.g. `1-5,8,12-`")
+            }
+            DaySetFromStrError::InvalidDay(day) => {
+                write!(f, "expecting a day number between 1 and 25, found `{day}`")
+            }
+            DaySetFromStrError::InvalidRange(range) => {
+                write!(f, "the range `{range}` ends before it starts")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
 /// Creates a [`Day`] value in a const context.
 #[macro_export]
 macro_rules! day {
@@ -132,9 +293,9 @@ macro_rules! day {
 
 /* -------------------------------------------------------------------------- */
 
-#[cfg(feature = "test_lib")]
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
-    use super::{all_days, Day};
+    use super::{all_days, Day, DaySet, DaySetFromStrError};
 
     #[test]
     fn all_days_iterator() {
@@ -167,6 +328,53 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn parses_day_sets() {
+        let days: DaySet = "1-5,8,12-".parse().unwrap();
+        let expected: Vec<u8> = (1..=5).chain([8]).chain(12..=25).collect();
+        assert_eq!(
+            days.iter().map(Day::into_inner).collect::<Vec<_>>(),
+            expected
+        );
+
+        assert_eq!("-3".parse::<DaySet>().unwrap().to_string(), "1-3");
+        assert_eq!("1-25".parse::<DaySet>().unwrap(), DaySet::all());
+        assert_eq!(" 7 , 6 ".parse::<DaySet>().unwrap().to_string(), "6,7");
+        assert_eq!("4-4".parse::<DaySet>().unwrap().to_string(), "4");
+    }
+
+    #[test]
+    fn rejects_invalid_day_sets() {
+        assert_eq!("".parse::<DaySet>(), Err(DaySetFromStrError::Empty));
+        assert_eq!("1,,2".parse::<DaySet>(), Err(DaySetFromStrError::Empty));
+        assert_eq!(
+            "0-3".parse::<DaySet>(),
+            Err(DaySetFromStrError::InvalidDay("0".into()))
+        );
+        assert_eq!(
+            "26".parse::<DaySet>(),
+            Err(DaySetFromStrError::InvalidDay("26".into()))
+        );
+        assert_eq!(
+            "x".parse::<DaySet>(),
+            Err(DaySetFromStrError::InvalidDay("x".into()))
+        );
+        assert_eq!(
+            "5-3".parse::<DaySet>(),
+            Err(DaySetFromStrError::InvalidRange("5-3".into()))
+        );
+    }
+
+    #[test]
+    fn combines_day_sets() {
+        let days: DaySet = "1-10".parse().unwrap();
+        let skip: DaySet = "3,5-".parse().unwrap();
+        assert_eq!(days.difference(&skip).to_string(), "1,2,4");
+        assert!(DaySet::empty().is_empty());
+        assert!(!DaySet::all().difference(&days).contains(Day(10)));
+        assert!(DaySet::all().difference(&days).contains(Day(11)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
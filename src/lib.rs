@@ -1,4 +1,13 @@
+mod answer;
 mod day;
+pub mod ocr;
+mod part;
+mod solution;
+pub mod solutions;
 pub mod template;
+pub mod testing;
 
+pub use answer::*;
 pub use day::*;
+pub use part::*;
+pub use solution::Solution;
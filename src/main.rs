@@ -1,10 +1,16 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve};
+use advent_of_code::template::commands::{
+    all, bundle, download, gen_input, read, scaffold, solve, stars, status, verify_builds,
+};
+use advent_of_code::template::config;
 use args::{parse, AppArguments};
 
 mod args {
     use std::process;
 
-    use advent_of_code::Day;
+    use advent_of_code::template::bench::{self, BenchOptions};
+    use advent_of_code::template::commands::{all, solve};
+    use advent_of_code::template::config;
+    use advent_of_code::{Day, DaySet};
 
     pub enum AppArguments {
         Download {
@@ -15,26 +21,98 @@ mod args {
         },
         Scaffold {
             day: Day,
+            lib: bool,
         },
         Solve {
             day: Day,
-            release: bool,
-            time: bool,
-            submit: Option<u8>,
+            options: solve::Options,
         },
         All {
-            release: bool,
-            time: bool,
+            options: all::Options,
         },
+        Stars,
+        Status {
+            json: bool,
+        },
+        VerifyBuilds {
+            overflow_checks: bool,
+        },
+        Bundle {
+            out: Option<String>,
+        },
+        GenInput {
+            day: Day,
+            scale: usize,
+            seed: Option<u64>,
+        },
+    }
+
+    fn parse_scales(s: &str) -> Result<Vec<usize>, String> {
+        s.split(',')
+            .map(|x| bench::parse_positive("scale", x.trim()))
+            .collect()
+    }
+
+    /// Counts `-v`, `-vv`, `-vvv` and `--verbose`.
+    fn parse_verbosity(args: &mut pico_args::Arguments) -> u8 {
+        let mut verbosity = 0;
+        for (flag, level) in [("-vvv", 3), ("-vv", 2)] {
+            while args.contains(flag) {
+                verbosity += level;
+            }
+        }
+        while args.contains(["-v", "--verbose"]) {
+            verbosity += 1;
+        }
+        verbosity
+    }
+
+    fn parse_bench_options(
+        args: &mut pico_args::Arguments,
+    ) -> Result<BenchOptions, pico_args::Error> {
+        Ok(BenchOptions {
+            bench_time: args.opt_value_from_fn("--bench-time", bench::parse_bench_time)?,
+            min_samples: args.opt_value_from_fn("--min-samples", bench::parse_samples)?,
+            max_samples: args.opt_value_from_fn("--max-samples", bench::parse_samples)?,
+            warmup: args.opt_value_from_fn("--warmup", bench::parse_warmup)?,
+        })
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
-            Some("all") => AppArguments::All {
-                release: args.contains("--release"),
-                time: args.contains("--time"),
+            Some("all") => {
+                let days: DaySet = args.opt_value_from_str("--days")?.unwrap_or(DaySet::all());
+                let skip: DaySet = args.opt_value_from_str("--skip")?.unwrap_or_default();
+                let project = config::project();
+
+                AppArguments::All {
+                    options: all::Options {
+                        release: args.contains("--release") || project.all.release,
+                        time: args.contains("--time") || project.all.time,
+                        chart: args.contains("--chart"),
+                        count_allocations: args.contains("--memory"),
+                        threads: args.opt_value_from_fn("--threads", |s| {
+                            bench::parse_positive("thread count", s)
+                        })?,
+                        check_determinism: args.opt_value_from_fn("--check-determinism", |s| {
+                            bench::parse_positive("run count", s)
+                        })?,
+                        scales: args.opt_value_from_fn("--scales", parse_scales)?,
+                        days: days.difference(&skip),
+                        jobs: args
+                            .opt_value_from_fn("--jobs", |s| bench::parse_positive("job count", s))?
+                            .unwrap_or(project.jobs),
+                        allow_noisy_timings: args.contains("--allow-noisy-timings"),
+                        bench: parse_bench_options(&mut args)?,
+                    },
+                }
+            }
+            Some("gen-input") => AppArguments::GenInput {
+                day: args.free_from_str()?,
+                scale: args.opt_value_from_str("--scale")?.unwrap_or(1),
+                seed: args.opt_value_from_str("--seed")?,
             },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
@@ -44,12 +122,39 @@ mod args {
             },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
+                lib: args.contains("--lib"),
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                time: args.contains("--time"),
+                options: solve::Options {
+                    release: args.contains("--release") || config::project().solve.release,
+                    submit_part: args.opt_value_from_str("--submit")?,
+                    time: args.contains("--time") || config::project().solve.time,
+                    count_allocations: args.contains("--memory"),
+                    threads: args.opt_value_from_fn("--threads", |s| {
+                        bench::parse_positive("thread count", s)
+                    })?,
+                    scaling: args.contains("--scaling"),
+                    profile: args.contains("--profile"),
+                    check_determinism: args.opt_value_from_fn("--check-determinism", |s| {
+                        bench::parse_positive("run count", s)
+                    })?,
+                    wait: args.contains("--wait"),
+                    variant: args.opt_value_from_str("--variant")?,
+                    input: args.opt_value_from_str("--input")?,
+                    verbosity: parse_verbosity(&mut args),
+                    bench: parse_bench_options(&mut args)?,
+                },
+            },
+            Some("stars") => AppArguments::Stars,
+            Some("status") => AppArguments::Status {
+                json: args.contains("--json"),
+            },
+            Some("verify-builds") => AppArguments::VerifyBuilds {
+                overflow_checks: args.contains("--overflow-checks"),
+            },
+            Some("bundle") => AppArguments::Bundle {
+                out: args.opt_value_from_str("--out")?,
             },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
@@ -71,22 +176,29 @@ mod args {
 }
 
 fn main() {
+    if let Err(e) = config::load_project() {
+        eprintln!("Invalid configuration: {e}");
+        std::process::exit(1);
+    }
+
     match parse() {
         Err(err) => {
             eprintln!("Error: {err}");
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release, time } => all::handle(release, time),
+            AppArguments::All { options } => all::handle(options),
             AppArguments::Download { day } => download::handle(day),
             AppArguments::Read { day } => read::handle(day),
-            AppArguments::Scaffold { day } => scaffold::handle(day),
-            AppArguments::Solve {
-                day,
-                release,
-                time,
-                submit,
-            } => solve::handle(day, release, time, submit),
+            AppArguments::Scaffold { day, lib } => scaffold::handle(day, lib),
+            AppArguments::Stars => stars::handle(),
+            AppArguments::Status { json } => status::handle(json),
+            AppArguments::VerifyBuilds { overflow_checks } => {
+                verify_builds::handle(overflow_checks);
+            }
+            AppArguments::Bundle { out } => bundle::handle(out),
+            AppArguments::GenInput { day, scale, seed } => gen_input::handle(day, scale, seed),
+            AppArguments::Solve { day, options } => solve::handle(day, options),
         },
     };
 }
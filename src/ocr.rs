@@ -0,0 +1,250 @@
+//! Recognizes the block letters some puzzles render their answers in.
+//!
+//! Two fonts are supported: the small one (4x6 letters in cells of 5 columns)
+//! and the large one (6x10 letters in cells of 8 columns). Lit pixels are `#` or `█`,
+//! everything else is treated as dark.
+//!
+//! ```
+//! # use advent_of_code::ocr;
+//! let art = [
+//!     "#..#.####",
+//!     "#..#.#...",
+//!     "####.###.",
+//!     "#..#.#...",
+//!     "#..#.#...",
+//!     "#..#.####",
+//! ];
+//! assert_eq!(ocr::recognize(&art.join("\n")).unwrap(), "HE");
+//! ```
+
+use std::error::Error;
+use std::fmt::Display;
+
+struct Font {
+    height: usize,
+    width: usize,
+    /// Horizontal distance between the left edges of two letters.
+    stride: usize,
+    letters: &'static str,
+    /// All `letters` rendered next to each other.
+    sheet: &'static str,
+}
+
+impl Font {
+    /// Returns the pixels of the letter at `index` in a grid, row by row.
+    fn glyph(&self, pixel: impl Fn(usize, usize) -> bool, index: usize) -> String {
+        let offset = index * self.stride;
+        (0..self.height)
+            .flat_map(|row| (offset..offset + self.width).map(move |col| (row, col)))
+            .map(|(row, col)| if pixel(row, col) { '#' } else { '.' })
+            .collect()
+    }
+
+    fn letter(&self, glyph: &str) -> Option<char> {
+        let sheet: Vec<&[u8]> = self.sheet.lines().map(str::as_bytes).collect();
+        let pixel = |row: usize, col: usize| sheet[row].get(col) == Some(&b'#');
+
+        self.letters
+            .chars()
+            .enumerate()
+            .find(|(index, _)| self.glyph(pixel, *index) == glyph)
+            .map(|(_, letter)| letter)
+    }
+}
+
+const SMALL: Font = Font {
+    height: 6,
+    width: 4,
+    stride: 5,
+    letters: "ABCEFGHIJKLOPRSUYZ",
+    sheet: "\
+.##..###...##..####.####..##..#..#..###...##.#..#.#.....##..###..###...###.#..#.#....####
+#..#.#..#.#..#.#....#....#..#.#..#...#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#.......#
+#..#.###..#....###..###..#....####...#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#...#.
+####.#..#.#....#....#....#.##.#..#...#.....#.#.#..#....#..#.###..###...##..#..#...#...#..
+#..#.#..#.#..#.#....#....#..#.#..#...#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#..#...
+#..#.###...##..####.#.....###.#..#..###..##..#..#.####..##..#....#..#.###...##....#..####",
+};
+
+const LARGE: Font = Font {
+    height: 10,
+    width: 6,
+    stride: 8,
+    letters: "ABCEFGHJKLNPRXZ",
+    sheet: "\
+..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######
+.#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#
+#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#
+#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#.
+#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#..
+######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#...
+#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#....
+#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.....
+#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.....
+#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######",
+};
+
+/// An error which can be returned when recognizing letters.
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    /// The art is neither 6 nor 10 rows high.
+    UnsupportedHeight(usize),
+    /// The letter at `index` (0-based) does not match any glyph of the font.
+    UnknownGlyph { index: usize, glyph: String },
+}
+
+impl Error for OcrError {}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::UnsupportedHeight(height) => write!(
+                f,
+                "expecting letters that are 6 or 10 rows high, found {height} rows"
+            ),
+            OcrError::UnknownGlyph { index, glyph } => {
+                write!(f, "could not recognize letter {}:\n{glyph}", index + 1)
+            }
+        }
+    }
+}
+
+/// Recognizes the letters in a rendered `&str`, see the [module docs](self).
+pub fn recognize(art: &str) -> Result<String, OcrError> {
+    let grid: Vec<Vec<bool>> = art
+        .lines()
+        .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+        .collect();
+
+    recognize_grid(&grid)
+}
+
+/// Recognizes the letters in a grid of pixels, indexed as `grid[row][col]`.
+/// Rows may have different lengths, missing pixels are dark.
+pub fn recognize_grid<R: AsRef<[bool]>>(grid: &[R]) -> Result<String, OcrError> {
+    let is_blank = |row: &R| !row.as_ref().contains(&true);
+
+    // blank rows around the letters are not part of the font.
+    let first = grid.iter().position(|row| !is_blank(row)).unwrap_or(0);
+    let last = grid
+        .iter()
+        .rposition(|row| !is_blank(row))
+        .map_or(0, |i| i + 1);
+    let rows = &grid[first..last.max(first)];
+
+    let font = match rows.len() {
+        6 => &SMALL,
+        10 => &LARGE,
+        height => return Err(OcrError::UnsupportedHeight(height)),
+    };
+
+    let pixel = |row: usize, col: usize| rows[row].as_ref().get(col).copied().unwrap_or(false);
+    let cols = rows.iter().map(|row| row.as_ref().len()).max().unwrap_or(0);
+    let n_letters = cols.div_ceil(font.stride);
+
+    (0..n_letters)
+        .filter_map(|index| {
+            let glyph = font.glyph(pixel, index);
+
+            // trailing cells that are fully dark are padding.
+            if index > 0 && !glyph.contains('#') {
+                return None;
+            }
+
+            Some(font.letter(&glyph).ok_or_else(|| {
+                OcrError::UnknownGlyph {
+                    index,
+                    glyph: glyph
+                        .as_bytes()
+                        .chunks(font.width)
+                        .map(|row| String::from_utf8_lossy(row).into_owned())
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                }
+            }))
+        })
+        .collect()
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{recognize, recognize_grid, Font, OcrError, LARGE, SMALL};
+
+    fn render(font: &Font, text: &str, lit: char) -> String {
+        let sheet: Vec<&str> = font.sheet.lines().collect();
+
+        sheet
+            .iter()
+            .map(|row| {
+                text.chars()
+                    .map(|c| {
+                        let start = font.letters.find(c).unwrap() * font.stride;
+                        let line: String = row[start..start + font.width]
+                            .chars()
+                            .map(|p| if p == '#' { lit } else { ' ' })
+                            .collect();
+                        format!("{line:<width$}", width = font.stride)
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn sheets_have_font_size() {
+        for font in [&SMALL, &LARGE] {
+            let width = font.letters.len() * font.stride - (font.stride - font.width);
+            assert_eq!(font.sheet.lines().count(), font.height);
+            assert!(font.sheet.lines().all(|row| row.len() == width));
+        }
+    }
+
+    #[test]
+    fn recognizes_small_font() {
+        let alphabet = SMALL.letters;
+        assert_eq!(recognize(&render(&SMALL, alphabet, '#')).unwrap(), alphabet);
+        assert_eq!(recognize(&render(&SMALL, "RGZ", '█')).unwrap(), "RGZ");
+    }
+
+    #[test]
+    fn recognizes_large_font() {
+        let alphabet = LARGE.letters;
+        assert_eq!(recognize(&render(&LARGE, alphabet, '#')).unwrap(), alphabet);
+    }
+
+    #[test]
+    fn recognizes_example() {
+        let art = [
+            "###..#....###...##..####.###...##..#....",
+            "#..#.#....#..#.#..#.#....#..#.#..#.#....",
+            "#..#.#....#..#.#..#.###..###..#....#....",
+            "###..#....###..####.#....#..#.#....#....",
+            "#.#..#....#.#..#..#.#....#..#.#..#.#....",
+            "#..#.####.#..#.#..#.####.###...##..####.",
+        ]
+        .join("\n");
+        assert_eq!(recognize(&art).unwrap(), "RLRAEBCL");
+    }
+
+    #[test]
+    fn recognizes_grid_with_blank_rows() {
+        let mut grid: Vec<Vec<bool>> = render(&SMALL, "HI", '#')
+            .lines()
+            .map(|l| l.chars().map(|c| c == '#').collect())
+            .collect();
+        grid.insert(0, vec![]);
+        grid.push(vec![false; 3]);
+        assert_eq!(recognize_grid(&grid).unwrap(), "HI");
+    }
+
+    #[test]
+    fn reports_errors() {
+        assert_eq!(recognize("#\n#"), Err(OcrError::UnsupportedHeight(2)));
+        assert_eq!(recognize(""), Err(OcrError::UnsupportedHeight(0)));
+        let err = recognize("####\n####\n####\n####\n####\n####").unwrap_err();
+        assert!(matches!(err, OcrError::UnknownGlyph { index: 0, .. }));
+    }
+}
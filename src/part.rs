@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// A part of a puzzle, either the first or the second.
+///
+/// # Display
+/// This value displays as its number, e.g. in `01-2.txt`.
+///
+/// ```
+/// # use advent_of_code::Part;
+/// let part: Part = "2".parse().unwrap();
+/// assert_eq!(part, Part::Two);
+/// assert_eq!(part.to_string(), "2")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    /// Creates a [`Part`] from the provided number if it is 1 or 2,
+    /// returns [`None`] otherwise.
+    pub fn new(part: u8) -> Option<Self> {
+        match part {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    // Not part of the public API
+    #[doc(hidden)]
+    pub const fn __new_unchecked(part: u8) -> Self {
+        match part {
+            1 => Part::One,
+            _ => Part::Two,
+        }
+    }
+
+    /// Converts the [`Part`] into its number.
+    pub fn into_inner(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.into_inner())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Part {
+    type Err = PartFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let part = s.parse().map_err(|_| PartFromStrError)?;
+        Self::new(part).ok_or(PartFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Part`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct PartFromStrError;
+
+impl Error for PartFromStrError {}
+
+impl Display for PartFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a part number, either 1 or 2")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Part`] value in a const context.
+#[macro_export]
+macro_rules! part {
+    ($part:expr) => {{
+        const _ASSERT: () = assert!(
+            $part == 1 || $part == 2,
+            concat!("invalid part number `", $part, "`, expecting 1 or 2"),
+        );
+        $crate::Part::__new_unchecked($part)
+    }};
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Part, PartFromStrError};
+
+    #[test]
+    fn parses_parts() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert_eq!("0".parse::<Part>(), Err(PartFromStrError));
+        assert_eq!("7".parse::<Part>(), Err(PartFromStrError));
+        assert_eq!("two".parse::<Part>(), Err(PartFromStrError));
+    }
+
+    #[test]
+    fn converts_parts() {
+        assert_eq!(crate::part!(1), Part::One);
+        assert_eq!(crate::part!(2), Part::Two);
+        assert_eq!(Part::new(2).map(Part::into_inner), Some(2));
+        assert_eq!(Part::new(3), None);
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+}
+
+/* -------------------------------------------------------------------------- */
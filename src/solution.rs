@@ -0,0 +1,33 @@
+use crate::{Answer, Day};
+
+/// A day's solution as a library module, see [`crate::solutions`].
+///
+/// [`Solution::solve`] parses the input once and passes it to both parts, e.g. day 5 parses
+/// the seeds and maps of the almanac. Solutions whose parts read the input differently, like
+/// day 1, use the raw input, i.e. `type Input<'a> = &'a str`.
+///
+/// ```
+/// use advent_of_code::{solutions::day09, Solution};
+///
+/// let input = "0 3 6 9 12 15\n";
+/// assert_eq!(day09::Solver::solve(input).0, Some(18_i64.into()));
+/// ```
+pub trait Solution {
+    const DAY: Day;
+    /// The title of the puzzle.
+    const NAME: &'static str;
+
+    type Input<'a>;
+
+    fn parse(input: &str) -> Self::Input<'_>;
+
+    fn part_one(input: &Self::Input<'_>) -> Option<Answer>;
+
+    fn part_two(input: &Self::Input<'_>) -> Option<Answer>;
+
+    /// Parses the input once and solves both parts.
+    fn solve(input: &str) -> (Option<Answer>, Option<Answer>) {
+        let input = Self::parse(input);
+        (Self::part_one(&input), Self::part_two(&input))
+    }
+}
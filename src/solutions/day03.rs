@@ -0,0 +1,212 @@
+/// Day 3: Gear Ratios
+use crate::{Answer, Day, Solution};
+
+/// The current day.
+pub const DAY: Day = crate::day!(3);
+
+/// A symbol of the engine schematic.
+pub struct SymbolPosition {
+    c: char,
+    row: usize,
+    col: usize,
+}
+
+/// A number of the engine schematic and the columns it spans.
+pub struct NumberPosition {
+    n: u32,
+    row: usize,
+    col_start: usize,
+    col_end: usize,
+}
+
+fn parse_input(input: &str) -> (Vec<SymbolPosition>, Vec<NumberPosition>) {
+    let mut symbols: Vec<SymbolPosition> = vec![];
+    let mut numbers: Vec<NumberPosition> = vec![];
+
+    for (i, line) in input.lines().enumerate() {
+        let mut col_start: Option<usize> = None;
+
+        for (j, c) in line.char_indices() {
+            if !c.is_ascii_digit() {
+                if let Some(k) = col_start {
+                    numbers.push(NumberPosition {
+                        n: line[k..j].parse().unwrap(),
+                        row: i,
+                        col_start: k,
+                        col_end: j - 1,
+                    });
+                    col_start = None;
+                }
+            }
+            if c == '.' {
+                continue;
+            }
+            if c.is_ascii_digit() {
+                if col_start.is_none() {
+                    col_start = Some(j);
+                }
+                continue;
+            }
+            symbols.push(SymbolPosition { c, row: i, col: j });
+        }
+
+        if let Some(k) = col_start {
+            numbers.push(NumberPosition {
+                n: line[k..].parse().unwrap(),
+                row: i,
+                col_start: k,
+                col_end: line.len() - 1,
+            });
+        }
+    }
+
+    (symbols, numbers)
+}
+
+fn is_adjected(n: &NumberPosition, s: &SymbolPosition) -> bool {
+    s.row.abs_diff(n.row) <= 1 && n.col_start <= s.col + 1 && s.col <= n.col_end + 1
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    let (symbols, numbers) = parse_input(input);
+    sum_part_numbers(&symbols, &numbers)
+}
+
+/// The sum of the numbers that are adjacent to a symbol.
+fn sum_part_numbers(symbols: &[SymbolPosition], numbers: &[NumberPosition]) -> Option<u32> {
+    let numbers = numbers.iter().filter_map(|n| {
+        let valid = symbols.iter().any(|s| is_adjected(n, s));
+        if valid {
+            Some(n.n)
+        } else {
+            None
+        }
+    });
+
+    Some(numbers.sum())
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    let (symbols, numbers) = parse_input(input);
+    sum_gear_ratios(&symbols, &numbers)
+}
+
+/// The sum of the products of the two numbers that are adjacent to a `*`.
+fn sum_gear_ratios(symbols: &[SymbolPosition], numbers: &[NumberPosition]) -> Option<u32> {
+    let gear_ratios = symbols.iter().filter_map(|s| {
+        if s.c != '*' {
+            return None;
+        }
+        let adj_numbers: Vec<u32> = numbers
+            .iter()
+            .filter_map(|n| if is_adjected(n, s) { Some(n.n) } else { None })
+            .collect();
+        if adj_numbers.len() < 2 {
+            return None;
+        }
+        Some(adj_numbers.iter().product::<u32>())
+    });
+    Some(gear_ratios.sum())
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: Day = DAY;
+    const NAME: &'static str = "Gear Ratios";
+
+    type Input<'a> = (Vec<SymbolPosition>, Vec<NumberPosition>);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input)
+    }
+
+    fn part_one((symbols, numbers): &Self::Input<'_>) -> Option<Answer> {
+        sum_part_numbers(symbols, numbers).map(Answer::from)
+    }
+
+    fn part_two((symbols, numbers): &Self::Input<'_>) -> Option<Answer> {
+        sum_gear_ratios(symbols, numbers).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(4361));
+    }
+
+    #[test]
+    fn test_is_adjected_properties() {
+        use crate::testing::{check, int};
+
+        let number = (int(0..=5_usize), int(0..=8_usize), int(0..=2_usize));
+        let symbol = (int(0..=6_usize), int(0..=11_usize));
+
+        check(
+            &(number, symbol),
+            |((row, col_start, width), (s_row, s_col))| {
+                let n = NumberPosition {
+                    n: 1,
+                    row: *row,
+                    col_start: *col_start,
+                    col_end: col_start + width,
+                };
+                let s = SymbolPosition {
+                    c: '*',
+                    row: *s_row,
+                    col: *s_col,
+                };
+                // a symbol is adjacent if it touches any digit, including diagonally.
+                let touches_digit = (n.col_start..=n.col_end)
+                    .any(|col| n.row.abs_diff(s.row) <= 1 && col.abs_diff(s.col) <= 1);
+
+                is_adjected(&n, &s) == touches_digit
+            },
+        );
+    }
+
+    #[test]
+    fn test_parse_input_properties() {
+        use crate::testing::{check, grid, one_of};
+
+        let cell = one_of(&['.', '.', '1', '7', '0', '*', '#']);
+
+        check(&grid(cell, 1..=6, 1..=8), |grid| {
+            let lines: Vec<String> = grid.iter().map(|row| row.iter().collect()).collect();
+            let (symbols, numbers) = parse_input(&lines.join("\n"));
+
+            let numbers_match = numbers.iter().all(|n| {
+                let line = &lines[n.row];
+                let before = n
+                    .col_start
+                    .checked_sub(1)
+                    .and_then(|i| line[i..].chars().next());
+                let after = line[n.col_end + 1..].chars().next();
+
+                line[n.col_start..=n.col_end].parse() == Ok(n.n)
+                    && !before.is_some_and(|c| c.is_ascii_digit())
+                    && !after.is_some_and(|c| c.is_ascii_digit())
+            });
+            let digits = grid.iter().flatten().filter(|c| c.is_ascii_digit()).count();
+            let parsed_digits: usize = numbers.iter().map(|n| n.col_end - n.col_start + 1).sum();
+            let n_symbols = grid
+                .iter()
+                .flatten()
+                .filter(|c| **c == '*' || **c == '#')
+                .count();
+
+            numbers_match && digits == parsed_digits && symbols.len() == n_symbols
+        });
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+}
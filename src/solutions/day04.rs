@@ -0,0 +1,284 @@
+/// Day 4: Scratchcards
+use std::collections::HashSet;
+use std::fmt::Display;
+
+use crate::{aoc_debug, Answer, Day, Solution};
+
+/// The current day.
+pub const DAY: Day = crate::day!(4);
+
+/// Numbers below this are kept in the bitset, which then never grows past 8 KiB.
+const BITSET_LIMIT: u32 = 1 << 16;
+
+/// A growable bitset used to look up the winning numbers of a card.
+/// Numbers from [`BITSET_LIMIT`] on are kept in a [`HashSet`] instead.
+#[derive(Debug, Default)]
+struct NumberSet {
+    words: Vec<u64>,
+    large: HashSet<u32>,
+}
+
+impl NumberSet {
+    fn insert(&mut self, n: u32) {
+        if n >= BITSET_LIMIT {
+            self.large.insert(n);
+            return;
+        }
+        let (word, bit) = (n as usize / 64, n % 64);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        self.words[word] |= 1 << bit;
+    }
+
+    fn contains(&self, n: u32) -> bool {
+        if n >= BITSET_LIMIT {
+            return self.large.contains(&n);
+        }
+        let (word, bit) = (n as usize / 64, n % 64);
+        self.words
+            .get(word)
+            .is_some_and(|bits| bits & (1 << bit) != 0)
+    }
+}
+
+/// A scratchcard with its winning numbers and the numbers you have.
+#[derive(Debug)]
+pub struct Card {
+    winning_numbers: NumberSet,
+    scratch_numbers: Vec<u32>,
+}
+
+impl Card {
+    fn matches(&self) -> usize {
+        self.scratch_numbers
+            .iter()
+            .filter(|scratch_number| self.winning_numbers.contains(**scratch_number))
+            .count()
+    }
+
+    fn score(&self) -> u32 {
+        let matches = self.matches() as u32;
+        match matches {
+            0 => 0,
+            _ => 2u32.pow(matches - 1),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum ParseCardError {
+    MissingPrefix,
+    MissingColon,
+    MissingSeparator,
+    InvalidNumber(String),
+}
+
+/// A [`ParseCardError`] together with the (1-based) line it occurred on.
+#[derive(Debug, PartialEq)]
+pub struct MalformedLine {
+    line: usize,
+    error: ParseCardError,
+}
+
+impl Display for MalformedLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "malformed card on line {}: ", self.line)?;
+        match &self.error {
+            ParseCardError::MissingPrefix => write!(f, "expected line to start with \"Card\""),
+            ParseCardError::MissingColon => write!(f, "missing ':' after the card id"),
+            ParseCardError::MissingSeparator => write!(f, "missing '|' between the numbers"),
+            ParseCardError::InvalidNumber(s) => write!(f, "\"{s}\" is not a valid number"),
+        }
+    }
+}
+
+fn parse_numbers(s: &str) -> Result<Vec<u32>, ParseCardError> {
+    s.split_whitespace()
+        .map(|n| {
+            n.parse()
+                .map_err(|_| ParseCardError::InvalidNumber(n.to_string()))
+        })
+        .collect()
+}
+
+fn get_card_from_line(line: &str) -> Result<Card, ParseCardError> {
+    let line = line
+        .strip_prefix("Card")
+        .ok_or(ParseCardError::MissingPrefix)?;
+    let (_, numbers) = line.split_once(':').ok_or(ParseCardError::MissingColon)?;
+    let (winning, scratch) = numbers
+        .split_once('|')
+        .ok_or(ParseCardError::MissingSeparator)?;
+
+    let mut winning_numbers = NumberSet::default();
+    for n in parse_numbers(winning)? {
+        winning_numbers.insert(n);
+    }
+
+    Ok(Card {
+        winning_numbers,
+        scratch_numbers: parse_numbers(scratch)?,
+    })
+}
+
+fn parse_cards(input: &str) -> Result<Vec<Card>, MalformedLine> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            get_card_from_line(line).map_err(|error| MalformedLine { line: i + 1, error })
+        })
+        .collect()
+}
+
+/// The parsed cards, reporting the malformed line if there is one.
+fn valid_cards(cards: &Result<Vec<Card>, MalformedLine>) -> Option<&[Card]> {
+    match cards {
+        Ok(cards) => Some(cards),
+        Err(e) => {
+            aoc_debug!("{e}");
+            None
+        }
+    }
+}
+
+pub fn part_one(input: &str) -> Option<u32> {
+    total_score(&parse_cards(input))
+}
+
+fn total_score(cards: &Result<Vec<Card>, MalformedLine>) -> Option<u32> {
+    let cards = valid_cards(cards)?;
+    Some(cards.iter().map(Card::score).sum())
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    total_cards(&parse_cards(input))
+}
+
+/// The number of cards, including the won copies.
+fn total_cards(cards: &Result<Vec<Card>, MalformedLine>) -> Option<u32> {
+    let cards = valid_cards(cards)?;
+    let mut copies = vec![1; cards.len()];
+
+    for (i, card) in cards.iter().enumerate() {
+        // cards never make you win copies of cards past the end of the table.
+        let won = (i + 1)..cards.len().min(i + 1 + card.matches());
+        for j in won {
+            copies[j] += copies[i];
+        }
+    }
+    Some(copies.iter().sum())
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: Day = DAY;
+    const NAME: &'static str = "Scratchcards";
+
+    type Input<'a> = Result<Vec<Card>, MalformedLine>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_cards(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<Answer> {
+        total_score(input).map(Answer::from)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<Answer> {
+        total_cards(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds `n_cards` cards with 40 winning and 100 scratch numbers each.
+    /// Every card except the last has exactly one match.
+    fn large_input(n_cards: usize) -> String {
+        (1..=n_cards)
+            .map(|id| {
+                let winning: Vec<String> = (100..140).map(|n| n.to_string()).collect();
+                let mut scratch: Vec<String> = (200..300).map(|n| n.to_string()).collect();
+                if id < n_cards {
+                    scratch[id % 100] = "117".into();
+                }
+                format!(
+                    "Card {id:>4}: {} | {}",
+                    winning.join(" "),
+                    scratch.join(" ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(30));
+    }
+
+    #[test]
+    fn test_parser() {
+        let card = get_card_from_line("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53").unwrap();
+        assert_eq!(card.scratch_numbers, vec![83, 86, 6, 31, 17, 9, 48, 53]);
+        assert!(card.winning_numbers.contains(41));
+        assert!(!card.winning_numbers.contains(6));
+        assert_eq!(card.matches(), 4);
+    }
+
+    #[test]
+    fn test_parser_malformed() {
+        assert_eq!(
+            get_card_from_line("Crd 1: 1 2 | 3").unwrap_err(),
+            ParseCardError::MissingPrefix
+        );
+        assert_eq!(
+            get_card_from_line("Card 1 1 2 | 3").unwrap_err(),
+            ParseCardError::MissingColon
+        );
+        assert_eq!(
+            get_card_from_line("Card 1: 1 2 3").unwrap_err(),
+            ParseCardError::MissingSeparator
+        );
+        assert_eq!(
+            get_card_from_line("Card 1: 1 x | 3").unwrap_err(),
+            ParseCardError::InvalidNumber("x".into())
+        );
+        assert_eq!(
+            parse_cards("Card 1: 1 | 1\nCard 2: 1 1").unwrap_err(),
+            MalformedLine {
+                line: 2,
+                error: ParseCardError::MissingSeparator
+            }
+        );
+        assert_eq!(part_one("Card 1: 1 | 1\nCard 2: 1 1"), None);
+    }
+
+    #[test]
+    fn test_large_numbers() {
+        let card = get_card_from_line("Card 1: 4000000000 17 | 4000000000 17 65536").unwrap();
+        assert!(card.winning_numbers.words.len() <= 1);
+        assert!(card.winning_numbers.contains(4_000_000_000));
+        assert!(!card.winning_numbers.contains(65536));
+        assert_eq!(card.matches(), 2);
+    }
+
+    #[test]
+    fn test_large_input() {
+        let input = large_input(1000);
+        assert_eq!(part_one(&input), Some(999));
+        assert_eq!(part_two(&input), Some(500_500));
+    }
+}
@@ -0,0 +1,600 @@
+/// Day 5: If You Give A Seed A Fertilizer
+use std::{cmp::Ordering, iter::zip, ops::Range};
+
+use rayon::prelude::*;
+
+use crate::{span, Answer, Day, Solution};
+
+/// The current day.
+pub const DAY: Day = crate::day!(5);
+
+/// A range of seeds, or of any category the seeds are mapped to.
+#[derive(Debug, PartialEq)]
+pub struct SeedRange {
+    start: usize,
+    end: usize,
+    length: usize,
+}
+
+impl SeedRange {
+    #[must_use]
+    pub fn new(start: usize, length: usize) -> SeedRange {
+        SeedRange {
+            start,
+            end: start + length,
+            length,
+        }
+    }
+}
+
+impl Clone for SeedRange {
+    fn clone(&self) -> Self {
+        SeedRange {
+            start: self.start,
+            end: self.end,
+            length: self.length,
+        }
+    }
+}
+
+/// A line of an almanac map, which shifts the values in `source` to `destination`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct MapRange {
+    source: Range<usize>,
+    destination: Range<usize>,
+    length: usize,
+    distance: isize,
+}
+
+impl MapRange {
+    #[must_use]
+    pub fn new(source: usize, destination: usize, length: usize) -> MapRange {
+        MapRange {
+            source: source..source + length,
+            destination: destination..destination + length,
+            length,
+            distance: destination as isize - source as isize,
+        }
+    }
+
+    /// Maps a value, if it is in the source range.
+    #[must_use]
+    pub fn get(&self, value: usize) -> Option<usize> {
+        if self.source.contains(&value) {
+            Some((value as isize + self.distance) as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Maps the part of a range that overlaps the source range.
+    #[must_use]
+    pub fn get_range(&self, range: &SeedRange) -> Option<SeedRange> {
+        // Intersect the range with the source of this map range and shift it by the distance.
+        let start = range.start.max(self.source.start);
+        let end = range.end.min(self.source.end);
+
+        if start >= end {
+            return None;
+        }
+
+        Some(SeedRange::new(
+            (start as isize + self.distance) as usize,
+            end - start,
+        ))
+    }
+}
+
+impl Ord for MapRange {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.source.start.cmp(&other.source.start)
+    }
+}
+
+impl PartialOrd for MapRange {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[allow(dead_code)]
+fn fill_gaps(map: &mut Vec<MapRange>) {
+    // Panic if map is empty
+    if map.is_empty() {
+        panic!("Map is empty");
+    }
+
+    // Sort the map
+    map.sort();
+
+    // Fill gap between 0 and first range
+    if map[0].source.start > 0 {
+        map.insert(0, MapRange::new(0, 0, map[0].source.start));
+    }
+
+    // Fill the gaps
+    let mut current: &MapRange;
+    let mut next: &MapRange;
+    for i in 0..map.len() - 1 {
+        current = &map[i];
+        next = &map[i + 1];
+        if current.source.end + 1 < next.source.start {
+            map.insert(
+                i + 1,
+                MapRange::new(
+                    current.source.end,
+                    current.source.end,
+                    next.source.start - current.source.end,
+                ),
+            );
+        }
+    }
+
+    // Fill gap between last range and MAX
+    let last = map[map.len() - 1].source.end;
+    if last < usize::MAX {
+        map.push(MapRange::new(last, last, usize::MAX - last));
+    }
+}
+
+fn reduce_ranges(mut ranges: Vec<SeedRange>) -> Vec<SeedRange> {
+    ranges.sort_by_key(|a| a.start);
+
+    let mut result: Vec<SeedRange> = Vec::new();
+    for current in ranges.iter() {
+        let Some(prev) = result.last() else {
+            result.push(current.clone());
+            continue;
+        };
+
+        if prev.end >= current.start {
+            // Remove prev from result and insert merged range
+            let new_range = SeedRange::new(prev.start, prev.end.max(current.end) - prev.start);
+            result.pop();
+            result.push(new_range);
+        } else {
+            result.push(current.clone());
+        }
+    }
+    result
+}
+
+fn get_from_map(map: &Vec<MapRange>, value: usize) -> Option<usize> {
+    for map_range in map {
+        if let Some(result) = map_range.get(value) {
+            return Some(result);
+        }
+    }
+    None
+}
+
+fn get_ranges_from_map(map: &Vec<MapRange>, range: &SeedRange) -> Vec<SeedRange> {
+    let mut result = Vec::new();
+    let mut used_maps = Vec::new();
+
+    for map_range in map {
+        if let Some(new_range) = map_range.get_range(range) {
+            result.push(new_range);
+            used_maps.push(map_range);
+        }
+    }
+
+    if result.is_empty() {
+        result.push(range.clone());
+        return result;
+    }
+
+    // Check if there are gap between the ranges
+    let mut mapped: Vec<(usize, usize)> = zip(result.iter(), used_maps.iter())
+        .map(|(new_range, used_map)| {
+            let orig_start = (new_range.start as isize - used_map.distance) as usize;
+            (orig_start, orig_start + new_range.length)
+        })
+        .collect();
+    mapped.sort_unstable();
+
+    let mut current_start = range.start;
+    let mut gap_result = Vec::new();
+
+    for (orig_start, orig_end) in mapped {
+        if orig_start > current_start {
+            gap_result.push(SeedRange::new(current_start, orig_start - current_start));
+        }
+        current_start = current_start.max(orig_end);
+    }
+
+    if current_start < range.end {
+        gap_result.push(SeedRange::new(current_start, range.end - current_start));
+    }
+
+    result.append(&mut gap_result);
+    result = reduce_ranges(result);
+    result
+}
+
+/// The maps of the almanac, from seed to location.
+pub struct Maps {
+    seed_soil: Vec<MapRange>,
+    soil_fertilizer: Vec<MapRange>,
+    fertilizer_water: Vec<MapRange>,
+    water_light: Vec<MapRange>,
+    light_temperature: Vec<MapRange>,
+    temperature_humidity: Vec<MapRange>,
+    humidity_location: Vec<MapRange>,
+}
+
+enum CurrentMap {
+    SeedSoil,
+    SoilFertilizer,
+    FertilizerWater,
+    WaterLight,
+    LightTemperature,
+    TemperatureHumidity,
+    HumidityLocation,
+}
+
+fn parse_input(input: &str) -> (Vec<SeedRange>, Maps) {
+    let mut seed_soil: Vec<MapRange> = Vec::new();
+    let mut soil_fertilizer: Vec<MapRange> = Vec::new();
+    let mut fertilizer_water: Vec<MapRange> = Vec::new();
+    let mut water_light: Vec<MapRange> = Vec::new();
+    let mut light_temperature: Vec<MapRange> = Vec::new();
+    let mut temperature_humidity: Vec<MapRange> = Vec::new();
+    let mut humidity_location: Vec<MapRange> = Vec::new();
+
+    let mut seeds: Vec<SeedRange> = Vec::new();
+    let mut current_map = None;
+
+    for line in input.lines() {
+        if line.is_empty() {
+            continue;
+        }
+        if line.starts_with("seeds: ") {
+            let nums: Vec<usize> = line
+                .split(" ")
+                .skip(1)
+                .map(|s| s.parse::<usize>().unwrap())
+                .collect();
+            for i in (0..nums.len()).step_by(2) {
+                seeds.push(SeedRange::new(nums[i], nums[i + 1]));
+            }
+            continue;
+        } else if line.starts_with("seed-to-soil map:") {
+            current_map = Some(CurrentMap::SeedSoil);
+            continue;
+        } else if line.starts_with("soil-to-fertilizer map:") {
+            current_map = Some(CurrentMap::SoilFertilizer);
+            continue;
+        } else if line.starts_with("fertilizer-to-water map:") {
+            current_map = Some(CurrentMap::FertilizerWater);
+            continue;
+        } else if line.starts_with("water-to-light map:") {
+            current_map = Some(CurrentMap::WaterLight);
+            continue;
+        } else if line.starts_with("light-to-temperature map:") {
+            current_map = Some(CurrentMap::LightTemperature);
+            continue;
+        } else if line.starts_with("temperature-to-humidity map:") {
+            current_map = Some(CurrentMap::TemperatureHumidity);
+            continue;
+        } else if line.starts_with("humidity-to-location map:") {
+            current_map = Some(CurrentMap::HumidityLocation);
+            continue;
+        }
+        let mut parts = line.split(" ");
+
+        let destination = parts.next().unwrap().parse::<usize>().unwrap();
+        let source = parts.next().unwrap().parse::<usize>().unwrap();
+        let length = parts.next().unwrap().parse::<usize>().unwrap();
+        let new_range = MapRange::new(source, destination, length);
+
+        match current_map {
+            Some(CurrentMap::SeedSoil) => seed_soil.push(new_range),
+            Some(CurrentMap::SoilFertilizer) => soil_fertilizer.push(new_range),
+            Some(CurrentMap::FertilizerWater) => fertilizer_water.push(new_range),
+            Some(CurrentMap::WaterLight) => water_light.push(new_range),
+            Some(CurrentMap::LightTemperature) => light_temperature.push(new_range),
+            Some(CurrentMap::TemperatureHumidity) => temperature_humidity.push(new_range),
+            Some(CurrentMap::HumidityLocation) => humidity_location.push(new_range),
+
+            None => panic!("No current map"),
+        }
+    }
+
+    (
+        seeds,
+        Maps {
+            seed_soil,
+            soil_fertilizer,
+            fertilizer_water,
+            water_light,
+            light_temperature,
+            temperature_humidity,
+            humidity_location,
+        },
+    )
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let (seeds, maps) = parse_input(input);
+    lowest_location(&seeds, &maps)
+}
+
+/// The lowest location of any seed, reading the seed ranges as single seeds.
+fn lowest_location(seeds: &[SeedRange], maps: &Maps) -> Option<u64> {
+    let seeds: Vec<usize> = seeds
+        .iter()
+        .flat_map(|seed| [seed.start, seed.length])
+        .collect();
+    Some(
+        seeds
+            .iter()
+            .map(|seed| {
+                let soil = get_from_map(&maps.seed_soil, *seed).unwrap_or(*seed);
+                let fertilizer = get_from_map(&maps.soil_fertilizer, soil).unwrap_or(soil);
+                let water = get_from_map(&maps.fertilizer_water, fertilizer).unwrap_or(fertilizer);
+                let light = get_from_map(&maps.water_light, water).unwrap_or(water);
+                let temperature = get_from_map(&maps.light_temperature, light).unwrap_or(light);
+                let humidity =
+                    get_from_map(&maps.temperature_humidity, temperature).unwrap_or(temperature);
+                let location = get_from_map(&maps.humidity_location, humidity).unwrap_or(humidity);
+                location as u64
+            })
+            .min()
+            .unwrap(),
+    )
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let (seeds, maps) = span!("parse_input", { parse_input(input) });
+    span!("map_seeds", { lowest_location_of_ranges(&seeds, &maps) })
+}
+
+/// The lowest location of any seed in the seed ranges.
+fn lowest_location_of_ranges(seeds: &[SeedRange], maps: &Maps) -> Option<u64> {
+    let seed_locs: Vec<SeedRange> = seeds
+        .iter()
+        .flat_map(|seed| {
+            let mut ranges = get_ranges_from_map(&maps.seed_soil, seed);
+            ranges = ranges
+                .iter()
+                .flat_map(|range| get_ranges_from_map(&maps.soil_fertilizer, range))
+                .collect();
+            ranges = reduce_ranges(ranges);
+            ranges = ranges
+                .iter()
+                .flat_map(|range| get_ranges_from_map(&maps.fertilizer_water, range))
+                .collect();
+            ranges = reduce_ranges(ranges);
+            ranges = ranges
+                .iter()
+                .flat_map(|range| get_ranges_from_map(&maps.water_light, range))
+                .collect();
+            ranges = reduce_ranges(ranges);
+            ranges = ranges
+                .iter()
+                .flat_map(|range| get_ranges_from_map(&maps.light_temperature, range))
+                .collect();
+            ranges = reduce_ranges(ranges);
+            ranges = ranges
+                .iter()
+                .flat_map(|range| get_ranges_from_map(&maps.temperature_humidity, range))
+                .collect();
+            ranges = reduce_ranges(ranges);
+            ranges = ranges
+                .iter()
+                .flat_map(|range| get_ranges_from_map(&maps.humidity_location, range))
+                .collect();
+            ranges = reduce_ranges(ranges);
+            ranges
+        })
+        .collect();
+
+    Some(
+        seed_locs
+            .iter()
+            .map(|range| range.start as u64)
+            .min()
+            .unwrap(),
+    )
+}
+
+/// Maps every single seed, which is only feasible for small inputs.
+pub fn part_two_bruteforce(input: &str) -> Option<u64> {
+    let (seeds, maps) = parse_input(input);
+    Some(
+        seeds
+            .par_iter()
+            .map(|seed_range| {
+                let mut min_location = usize::MAX;
+                for seed in seed_range.start..seed_range.end {
+                    let soil = get_from_map(&maps.seed_soil, seed).unwrap_or(seed);
+                    let fertilizer = get_from_map(&maps.soil_fertilizer, soil).unwrap_or(soil);
+                    let water =
+                        get_from_map(&maps.fertilizer_water, fertilizer).unwrap_or(fertilizer);
+                    let light = get_from_map(&maps.water_light, water).unwrap_or(water);
+                    let temperature = get_from_map(&maps.light_temperature, light).unwrap_or(light);
+                    let humidity = get_from_map(&maps.temperature_humidity, temperature)
+                        .unwrap_or(temperature);
+                    let location =
+                        get_from_map(&maps.humidity_location, humidity).unwrap_or(humidity);
+                    if location < min_location {
+                        min_location = location;
+                    }
+                }
+                min_location as u64
+            })
+            .min()
+            .unwrap(),
+    )
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: Day = DAY;
+    const NAME: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input<'a> = (Vec<SeedRange>, Maps);
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse_input(input)
+    }
+
+    fn part_one((seeds, maps): &Self::Input<'_>) -> Option<Answer> {
+        lowest_location(seeds, maps).map(Answer::from)
+    }
+
+    fn part_two((seeds, maps): &Self::Input<'_>) -> Option<Answer> {
+        lowest_location_of_ranges(seeds, maps).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_ranges_from_map() {
+        let map = vec![MapRange::new(10, 0, 100)];
+
+        // Test single range starting inside
+        let range = SeedRange::new(50, 100);
+        let result = get_ranges_from_map(&map, &range);
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0], SeedRange::new(40, 60));
+        assert_eq!(result[1], SeedRange::new(110, 40));
+
+        // Test single range completely inside
+        let range = SeedRange::new(20, 50);
+        let result = get_ranges_from_map(&map, &range);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0], SeedRange::new(10, 50));
+
+        // Test single range completely outside
+        let range = SeedRange::new(200, 50);
+        let result = get_ranges_from_map(&map, &range);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0], SeedRange::new(200, 50));
+    }
+
+    #[test]
+    fn test_reduce_ranges_properties() {
+        use crate::testing::{check, range, vec};
+
+        check(&vec(range(0..=100_usize, 20), 0..=8), |ranges| {
+            let seed_ranges = ranges
+                .iter()
+                .map(|r| SeedRange::new(r.start, r.len()))
+                .collect();
+            let reduced = reduce_ranges(seed_ranges);
+
+            let is_sorted_and_disjoint = reduced.windows(2).all(|w| w[0].end < w[1].start);
+            let covers_same_values = (0..=120).all(|x| {
+                ranges.iter().any(|r| r.contains(&x))
+                    == reduced.iter().any(|r| (r.start..r.end).contains(&x))
+            });
+            let has_valid_lengths = reduced.iter().all(|r| r.end - r.start == r.length);
+
+            is_sorted_and_disjoint && covers_same_values && has_valid_lengths
+        });
+    }
+
+    #[test]
+    fn test_fill_gaps() {
+        // Test single range
+        let mut map = vec![MapRange::new(10, 0, 100)];
+        fill_gaps(&mut map);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map[0], MapRange::new(0, 0, 10));
+        assert_eq!(map[1], MapRange::new(10, 0, 100));
+        assert_eq!(map[2], MapRange::new(110, 110, usize::MAX - 110));
+
+        // Test two ranges
+        let mut map = vec![MapRange::new(10, 0, 100), MapRange::new(200, 0, 100)];
+        fill_gaps(&mut map);
+        assert_eq!(map.len(), 5);
+        assert_eq!(map[0], MapRange::new(0, 0, 10));
+        assert_eq!(map[1], MapRange::new(10, 0, 100));
+        assert_eq!(map[2], MapRange::new(110, 110, 90));
+        assert_eq!(map[3], MapRange::new(200, 0, 100));
+        assert_eq!(map[4], MapRange::new(300, 300, usize::MAX - 300));
+
+        // Test map starting at 0
+        let mut map = vec![MapRange::new(0, 0, 100), MapRange::new(200, 0, 100)];
+        fill_gaps(&mut map);
+        assert_eq!(map.len(), 4);
+        assert_eq!(map[0], MapRange::new(0, 0, 100));
+        assert_eq!(map[1], MapRange::new(100, 100, 100));
+        assert_eq!(map[2], MapRange::new(200, 0, 100));
+        assert_eq!(map[3], MapRange::new(300, 300, usize::MAX - 300));
+
+        // Test map ending at MAX
+        let mut map = vec![
+            MapRange::new(10, 0, 100),
+            MapRange::new(usize::MAX - 100, 0, 100),
+        ];
+        fill_gaps(&mut map);
+        assert_eq!(map.len(), 4);
+        assert_eq!(map[0], MapRange::new(0, 0, 10));
+        assert_eq!(map[1], MapRange::new(10, 0, 100));
+        assert_eq!(map[2], MapRange::new(110, 110, usize::MAX - 110 - 100));
+        assert_eq!(map[3], MapRange::new(usize::MAX - 100, 0, 100));
+    }
+
+    #[test]
+    fn test_input_parser() {
+        let (seeds, mut maps) = parse_input(&crate::template::read_file("examples", DAY));
+        fill_gaps(maps.seed_soil.as_mut());
+        fill_gaps(maps.temperature_humidity.as_mut());
+        assert_eq!(seeds[0], SeedRange::new(79, 14));
+        assert_eq!(get_from_map(&maps.seed_soil, 98), Some(50));
+        assert_eq!(get_from_map(&maps.seed_soil, 99), Some(51));
+        assert_eq!(get_from_map(&maps.seed_soil, 53), Some(55));
+        assert_eq!(get_from_map(&maps.temperature_humidity, 69), Some(0));
+        assert_eq!(get_from_map(&maps.temperature_humidity, 2), Some(3));
+        assert_eq!(get_from_map(&maps.temperature_humidity, 70), Some(70));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(35));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(46));
+    }
+
+    #[test]
+    fn test_solve() {
+        let result = Solver::solve(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, (Some(35_u64.into()), Some(46_u64.into())));
+    }
+
+    #[test]
+    fn test_part_two_a() {
+        let result = part_two(&crate::template::read_file("inputs", DAY));
+        assert_eq!(result, Some(0));
+    }
+}
+
+/*
+location:
+0
+
+humidity:
+262282387
+
+temperature:
+502588504
+
+light:
+502588504
+
+water:
+
+
+*/
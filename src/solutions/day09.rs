@@ -0,0 +1,146 @@
+/// Day 9: Mirage Maintenance
+use crate::{Answer, Day, Solution};
+
+/// The current day.
+pub const DAY: Day = crate::day!(9);
+
+fn parse(input: &str) -> Vec<Vec<i64>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|measurement| measurement.parse::<i64>().unwrap())
+                .collect::<Vec<i64>>()
+        })
+        .collect()
+}
+
+fn create_walks(history: &[i64]) -> Vec<Vec<i64>> {
+    let mut walks: Vec<Vec<i64>> = Vec::new();
+    walks.push(history.to_vec());
+    loop {
+        let new_walk: Vec<i64> = walks
+            .last()
+            .unwrap()
+            .windows(2)
+            .map(|w| w[1] - w[0])
+            .collect();
+        let first_val = new_walk.first().unwrap();
+        let found = new_walk.iter().all(|x| x == first_val) || new_walk.len() == 1;
+        walks.push(new_walk);
+        if found {
+            break;
+        }
+    }
+    walks
+}
+
+pub fn part_one(input: &str) -> Option<i64> {
+    next_values(&parse(input))
+}
+
+/// The sum of the extrapolated next values of every history.
+fn next_values(hists: &[Vec<i64>]) -> Option<i64> {
+    let extrapolations = hists.iter().map(|history| {
+        let walks = create_walks(history);
+        let mut current_ext = 0;
+        for walk in walks.iter().rev() {
+            current_ext += walk.last().unwrap();
+        }
+        current_ext
+    });
+
+    Some(extrapolations.sum())
+}
+
+pub fn part_two(input: &str) -> Option<i64> {
+    previous_values(&parse(input))
+}
+
+/// The sum of the extrapolated previous values of every history.
+fn previous_values(hists: &[Vec<i64>]) -> Option<i64> {
+    let extrapolations = hists.iter().map(|history| {
+        let walks = create_walks(history);
+        let mut current_ext = 0;
+        for walk in walks.iter().rev() {
+            current_ext = walk.first().unwrap() - current_ext;
+        }
+        current_ext
+    });
+
+    Some(extrapolations.sum())
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: Day = DAY;
+    const NAME: &'static str = "Mirage Maintenance";
+
+    type Input<'a> = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<Answer> {
+        next_values(input).map(Answer::from)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<Answer> {
+        previous_values(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(114));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(2));
+    }
+
+    #[test]
+    fn test_create_walks_properties() {
+        use crate::testing::{check, int, vec};
+
+        check(&vec(int(-100..=100_i64), 2..=12), |history| {
+            let walks = create_walks(history);
+
+            let starts_with_history = walks[0] == *history;
+            let are_differences = walks.windows(2).all(|w| {
+                w[1].len() + 1 == w[0].len()
+                    && w[1]
+                        .iter()
+                        .enumerate()
+                        .all(|(i, d)| *d == w[0][i + 1] - w[0][i])
+            });
+            let last = walks.last().unwrap();
+            let ends_constant = last.len() == 1 || last.iter().all(|x| *x == last[0]);
+
+            starts_with_history && are_differences && ends_constant
+        });
+    }
+
+    #[test]
+    fn test_create_walks_polynomial() {
+        use crate::testing::{check, int, vec};
+
+        // a polynomial of degree d becomes constant after d differences.
+        check(&vec(int(-5..=5_i64), 1..=5), |coefficients| {
+            let history: Vec<i64> = (0..12_i64)
+                .map(|x| coefficients.iter().rev().fold(0, |acc, c| acc * x + c))
+                .collect();
+
+            create_walks(&history).len() <= coefficients.len() + 1
+        });
+    }
+}
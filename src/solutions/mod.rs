@@ -0,0 +1,59 @@
+/// Solutions as library modules, so other code can call them and `cargo test --lib` covers every day.
+///
+/// Each `dayDD` module exposes `part_one` and `part_two`, its [`Solution`] as `Solver` and the
+/// constant `DAY`. The binary in `src/bin/DD.rs` only imports the module and calls `solution!`.
+use crate::{Answer, Day, Solution};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day08;
+pub mod day09;
+
+/// Parses an input and solves both parts of a day, see [`Solution::solve`].
+pub type Solve = fn(&str) -> (Option<Answer>, Option<Answer>);
+
+/// Every solution module by day.
+const SOLUTIONS: &[(u8, Solve)] = &[
+    (1, day01::Solver::solve),
+    (2, day02::Solver::solve),
+    (3, day03::Solver::solve),
+    (4, day04::Solver::solve),
+    (5, day05::Solver::solve),
+    (6, day06::Solver::solve),
+    (8, day08::Solver::solve),
+    (9, day09::Solver::solve),
+];
+
+/// The solver of a day, if it has a solution module.
+#[must_use]
+pub fn get(day: Day) -> Option<Solve> {
+    SOLUTIONS
+        .iter()
+        .find(|(d, _)| day == *d)
+        .map(|(_, solve)| *solve)
+}
+
+/// The days that have a solution module.
+pub fn days() -> impl Iterator<Item = Day> {
+    SOLUTIONS.iter().filter_map(|(day, _)| Day::new(*day))
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::get;
+    use crate::{day, template::read_file, Answer};
+
+    #[test]
+    fn finds_solver_by_day() {
+        let solve = get(day!(9)).unwrap();
+        assert_eq!(
+            solve(&read_file("examples", day!(9))),
+            (Some(Answer::from(114)), Some(Answer::from(2)))
+        );
+        assert!(get(day!(7)).is_none());
+    }
+}
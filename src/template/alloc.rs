@@ -0,0 +1,184 @@
+/// Counts heap allocations, enabled by the `count_allocations` feature.
+///
+/// With the feature, the library installs [`CountingAllocator`] as global allocator,
+/// which forwards to the system allocator and keeps running totals.
+/// Without it, [`measure`] only runs the function and reports nothing.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(feature = "count_allocations")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+static PEAK_LIVE_BYTES: AtomicU64 = AtomicU64::new(0);
+
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        let size = size as u64;
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        LIVE_BYTES.fetch_sub(size as u64, Ordering::Relaxed);
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    /// A reallocation counts as a new allocation of `new_size` that frees the old one.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            Self::record_alloc(new_size);
+            Self::record_dealloc(layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made while running a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+    /// The maximum of bytes that were live at the same time, on top of what was live before.
+    pub peak_live_bytes: u64,
+}
+
+/// Runs `func` and returns the allocations it made, if counting is enabled.
+/// Allocations of other threads running at the same time are included.
+pub fn measure<T>(func: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+    if !cfg!(feature = "count_allocations") {
+        return (func(), None);
+    }
+
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let live_bytes = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(live_bytes, Ordering::Relaxed);
+
+    let result = func();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes,
+        peak_live_bytes: PEAK_LIVE_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(live_bytes),
+    };
+
+    (result, Some(stats))
+}
+
+/// Formats bytes with a binary unit, e.g. `1.5 KiB`.
+#[must_use]
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{value:.1} {}", UNITS[unit])
+}
+
+/// Parses the output of [`format_bytes`]. The result is rounded like the input.
+#[must_use]
+pub fn parse_bytes(s: &str) -> Option<u64> {
+    let (value, unit) = s.trim().split_once(' ')?;
+    let value: f64 = value.parse().ok()?;
+    let factor = match unit {
+        "B" => 1_u64,
+        "KiB" => 1 << 10,
+        "MiB" => 1 << 20,
+        "GiB" => 1 << 30,
+        "TiB" => 1 << 40,
+        _ => return None,
+    };
+
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    Some((value * factor as f64).round() as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{format_bytes, measure, parse_bytes};
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 << 20), "3.0 MiB");
+        assert_eq!(parse_bytes("1.5 KiB"), Some(1536));
+        assert_eq!(parse_bytes("1023 B"), Some(1023));
+        assert_eq!(parse_bytes("1.5"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "count_allocations")]
+    fn counts_allocations() {
+        let (_, stats) = measure(|| {
+            let a = vec![0_u8; 4096];
+            let b = vec![0_u8; 4096];
+            drop(a);
+            drop(b);
+            let c = vec![0_u8; 1024];
+            c.len()
+        });
+
+        let stats = stats.unwrap();
+        assert!(stats.allocations >= 3);
+        assert!(stats.allocated_bytes >= 9216);
+        // other test threads may allocate at the same time, so only lower bounds hold.
+        assert!(stats.peak_live_bytes >= 8192);
+    }
+
+    #[test]
+    #[cfg(not(feature = "count_allocations"))]
+    fn counts_nothing_when_disabled() {
+        assert_eq!(measure(|| vec![1, 2, 3].len()), (3, None));
+    }
+}
@@ -0,0 +1,52 @@
+/// Module that stores known correct answers in `data/answers`, one file per part.
+/// The folder can be changed with `paths.answers` in `aoc.toml`.
+use std::{fs, io, path::Path};
+
+use crate::template::config;
+use crate::{Answer, Day, Part};
+
+#[must_use]
+pub fn get_answer_path(day: Day, part: Part) -> String {
+    config::project()
+        .paths
+        .file("answers", &format!("{day}-{part}.txt"))
+}
+
+/// Reads the stored answer for a part, if there is one.
+#[must_use]
+pub fn read(day: Day, part: Part) -> Option<Answer> {
+    parse(&fs::read_to_string(get_answer_path(day, part)).ok()?)
+}
+
+fn parse(contents: &str) -> Option<Answer> {
+    let contents = contents.strip_suffix('\n').unwrap_or(contents);
+
+    if contents.is_empty() {
+        return None;
+    }
+
+    contents.parse().ok()
+}
+
+/// Stores `answer` as the correct answer for a part.
+pub fn write(day: Day, part: Part, answer: &Answer) -> io::Result<()> {
+    let path = get_answer_path(day, part);
+    if let Some(parent) = Path::new(&path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, format!("{answer}\n"))
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::parse;
+    use crate::Answer;
+
+    #[test]
+    fn matches_stored_answers() {
+        assert_eq!(parse("4361\n"), Some(Answer::from(4361_u32)));
+        assert_eq!(parse("4361\n"), Some(Answer::from("4361".to_string())));
+        assert_eq!(parse("ABCD\n"), Some(Answer::from("ABCD")));
+        assert_eq!(parse("\n"), None);
+    }
+}
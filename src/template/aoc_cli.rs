@@ -4,7 +4,9 @@ use std::{
     process::{Command, Output, Stdio},
 };
 
-use crate::Day;
+use super::config;
+use super::verdict::SubmissionVerdict;
+use crate::{Answer, Day, Part};
 
 #[derive(Debug)]
 pub enum AocCommandError {
@@ -74,27 +76,51 @@ pub fn download(day: Day) -> Result<Output, AocCommandError> {
     Ok(output)
 }
 
-pub fn submit(day: Day, part: u8, result: &str) -> Result<Output, AocCommandError> {
+/// Submits an answer. The response is printed and returned as parsed verdict, if recognized.
+pub fn submit(
+    day: Day,
+    part: Part,
+    result: &Answer,
+) -> Result<Option<SubmissionVerdict>, AocCommandError> {
     // workaround: the argument order is inverted for submit.
     let mut args = build_args("submit", &[], day);
     args.push(part.to_string());
     args.push(result.to_string());
-    call_aoc_cli(&args)
+
+    let output = Command::new("aoc")
+        .args(&args)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|_| AocCommandError::CommandNotCallable)?;
+
+    let response = String::from_utf8_lossy(&output.stdout);
+    print!("{response}");
+
+    if output.status.success() {
+        Ok(SubmissionVerdict::parse(&response))
+    } else {
+        Err(AocCommandError::BadExitStatus(output))
+    }
 }
 
-fn get_input_path(day: Day) -> String {
-    format!("data/inputs/{day}.txt")
+#[must_use]
+pub fn get_input_path(day: Day) -> String {
+    config::project()
+        .paths
+        .file("inputs", &format!("{day}.txt"))
 }
 
-fn get_puzzle_path(day: Day) -> String {
-    format!("data/puzzles/{day}.md")
+#[must_use]
+pub fn get_puzzle_path(day: Day) -> String {
+    config::project()
+        .paths
+        .file("puzzles", &format!("{day}.md"))
 }
 
-fn get_year() -> Option<u16> {
-    match std::env::var("AOC_YEAR") {
-        Ok(x) => x.parse().ok().or(None),
-        Err(_) => None,
-    }
+/// The year set in `aoc.toml` or `AOC_YEAR`.
+#[must_use]
+pub fn get_year() -> Option<u16> {
+    config::project().year
 }
 
 fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
@@ -105,6 +131,11 @@ fn build_args(command: &str, args: &[String], day: Day) -> Vec<String> {
         cmd_args.push(year.to_string());
     }
 
+    if let Some(session_file) = &config::project().session_file {
+        cmd_args.push("--session-file".into());
+        cmd_args.push(session_file.to_string_lossy().into_owned());
+    }
+
     cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
 
     cmd_args
@@ -0,0 +1,274 @@
+/// Settings for benchmarking parts with `--time`.
+use std::{env, str::FromStr, time::Duration};
+
+use crate::template::config::Config;
+use crate::template::readme_benchmarks::parse_duration;
+
+/// Runs shorter than this are timed in batches, so the overhead of `Instant::now` does not dominate.
+const BATCH_DURATION: Duration = Duration::from_micros(10);
+
+/// How parts are benched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchSettings {
+    /// Roughly how long to bench each part.
+    pub bench_time: Duration,
+    pub min_samples: u128,
+    pub max_samples: u128,
+    /// Runs before sampling that are not measured.
+    pub warmup: u32,
+}
+
+impl Default for BenchSettings {
+    fn default() -> Self {
+        BenchSettings {
+            bench_time: Duration::from_secs(1),
+            min_samples: 10,
+            max_samples: 10_000,
+            warmup: 0,
+        }
+    }
+}
+
+/// Bench settings passed on the command line, which take precedence over the `[bench]` section of `aoc.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub bench_time: Option<Duration>,
+    pub min_samples: Option<u128>,
+    pub max_samples: Option<u128>,
+    pub warmup: Option<u32>,
+}
+
+impl BenchOptions {
+    /// Reads the options passed to a solution binary.
+    pub fn from_env() -> Result<Self, String> {
+        let args: Vec<String> = env::args().collect();
+        let value = |flag: &str| {
+            let index = args.iter().position(|x| x == flag)?;
+            Some(
+                args.get(index + 1)
+                    .map(String::as_str)
+                    .ok_or(format!("missing value for `{flag}`")),
+            )
+        };
+
+        Ok(BenchOptions {
+            bench_time: value("--bench-time")
+                .map(|v| parse_bench_time(v?))
+                .transpose()?,
+            min_samples: value("--min-samples")
+                .map(|v| parse_samples(v?))
+                .transpose()?,
+            max_samples: value("--max-samples")
+                .map(|v| parse_samples(v?))
+                .transpose()?,
+            warmup: value("--warmup").map(|v| parse_warmup(v?)).transpose()?,
+        })
+    }
+
+    /// The arguments that pass these options on to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+
+        if let Some(bench_time) = self.bench_time {
+            args.push("--bench-time".into());
+            args.push(format!("{}ns", bench_time.as_nanos()));
+        }
+
+        if let Some(min_samples) = self.min_samples {
+            args.push("--min-samples".into());
+            args.push(min_samples.to_string());
+        }
+
+        if let Some(max_samples) = self.max_samples {
+            args.push("--max-samples".into());
+            args.push(max_samples.to_string());
+        }
+
+        if let Some(warmup) = self.warmup {
+            args.push("--warmup".into());
+            args.push(warmup.to_string());
+        }
+
+        args
+    }
+
+    /// Combines these options with the config and the defaults.
+    pub fn settings(&self, config: &Config) -> Result<BenchSettings, String> {
+        let defaults = BenchSettings::default();
+        let from_config = |key: &str| config.get_u64(key).map_err(|e| e.to_string());
+
+        let settings = BenchSettings {
+            bench_time: match self.bench_time {
+                Some(bench_time) => bench_time,
+                None => config
+                    .get_duration("bench.time")
+                    .map_err(|e| e.to_string())?
+                    .unwrap_or(defaults.bench_time),
+            },
+            min_samples: match self.min_samples {
+                Some(min_samples) => min_samples,
+                None => from_config("bench.min_samples")?.map_or(defaults.min_samples, u128::from),
+            },
+            max_samples: match self.max_samples {
+                Some(max_samples) => max_samples,
+                None => from_config("bench.max_samples")?.map_or(defaults.max_samples, u128::from),
+            },
+            warmup: match self.warmup {
+                Some(warmup) => warmup,
+                None => match from_config("bench.warmup")? {
+                    Some(warmup) => {
+                        u32::try_from(warmup).map_err(|_| "`bench.warmup` is too large")?
+                    }
+                    None => defaults.warmup,
+                },
+            },
+        };
+
+        if settings.min_samples == 0 {
+            return Err("the minimum number of samples must be at least 1".into());
+        }
+
+        if settings.min_samples > settings.max_samples {
+            return Err(format!(
+                "the minimum number of samples ({}) is larger than the maximum ({})",
+                settings.min_samples, settings.max_samples
+            ));
+        }
+
+        Ok(settings)
+    }
+}
+
+/// Parses a bench time like `2` (seconds), `1.5s` or `500ms`.
+pub fn parse_bench_time(s: &str) -> Result<Duration, String> {
+    parse_duration(s)
+        .filter(|nanos| nanos.is_finite() && *nanos > 0.0)
+        .and_then(|nanos| Duration::try_from_secs_f64(nanos / 1_000_000_000_f64).ok())
+        .ok_or(format!(
+            "invalid bench time `{s}`, expecting a duration like `2s` or `500ms`"
+        ))
+}
+
+pub fn parse_samples(s: &str) -> Result<u128, String> {
+    parse_positive("sample count", s)
+}
+
+/// Parses a positive integer, `name` describes the value in the error, e.g. `thread count`.
+pub fn parse_positive<T: FromStr + From<u8> + PartialEq>(name: &str, s: &str) -> Result<T, String> {
+    match s.parse() {
+        Ok(x) if x != T::from(0) => Ok(x),
+        _ => Err(format!(
+            "invalid {name} `{s}`, expecting a positive integer"
+        )),
+    }
+}
+
+pub fn parse_warmup(s: &str) -> Result<u32, String> {
+    s.parse()
+        .map_err(|_| format!("invalid warmup `{s}`, expecting the number of runs"))
+}
+
+/// How many times to run a part, and how many of those runs are timed together.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plan {
+    pub batches: u128,
+    pub batch_size: u128,
+}
+
+impl Plan {
+    #[must_use]
+    pub fn samples(&self) -> u128 {
+        self.batches * self.batch_size
+    }
+}
+
+/// Fills the bench time with runs that take about `run_time` each, within the sample limits.
+#[must_use]
+pub fn plan(settings: &BenchSettings, run_time: Duration) -> Plan {
+    let run_nanos = run_time.as_nanos().max(1);
+    let samples = (settings.bench_time.as_nanos() / run_nanos)
+        .clamp(settings.min_samples, settings.max_samples);
+    let batch_size = (BATCH_DURATION.as_nanos() / run_nanos).clamp(1, samples);
+
+    Plan {
+        batches: samples
+            .div_ceil(batch_size)
+            .min(settings.max_samples / batch_size)
+            .max(1),
+        batch_size,
+    }
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{parse_bench_time, plan, BenchOptions, BenchSettings, Plan};
+    use crate::template::config::Config;
+    use std::time::Duration;
+
+    #[test]
+    fn parses_bench_time() {
+        assert_eq!(parse_bench_time("2"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_bench_time("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_bench_time("500ms"), Ok(Duration::from_millis(500)));
+        assert!(parse_bench_time("0").is_err());
+        assert!(parse_bench_time("soon").is_err());
+    }
+
+    #[test]
+    fn combines_options_with_config() {
+        let config = Config::parse("[bench]\ntime = \"2s\"\nmin_samples = 5\nwarmup = 3").unwrap();
+        let options = BenchOptions {
+            min_samples: Some(20),
+            ..BenchOptions::default()
+        };
+
+        assert_eq!(
+            options.settings(&config),
+            Ok(BenchSettings {
+                bench_time: Duration::from_secs(2),
+                min_samples: 20,
+                max_samples: 10_000,
+                warmup: 3,
+            })
+        );
+
+        let options = BenchOptions {
+            min_samples: Some(100),
+            max_samples: Some(10),
+            ..BenchOptions::default()
+        };
+        assert!(options.settings(&Config::default()).is_err());
+    }
+
+    #[test]
+    fn plans_batches() {
+        let settings = BenchSettings::default();
+
+        // slow parts are sampled one run at a time, at least `min_samples` times.
+        assert_eq!(
+            plan(&settings, Duration::from_millis(500)),
+            Plan {
+                batches: 10,
+                batch_size: 1
+            }
+        );
+        assert_eq!(
+            plan(&settings, Duration::from_micros(200)),
+            Plan {
+                batches: 5000,
+                batch_size: 1
+            }
+        );
+
+        // fast parts are batched and capped at `max_samples`.
+        assert_eq!(
+            plan(&settings, Duration::from_nanos(50)),
+            Plan {
+                batches: 50,
+                batch_size: 200
+            }
+        );
+        assert_eq!(plan(&settings, Duration::from_nanos(30)).samples(), 9990);
+    }
+}
@@ -0,0 +1,346 @@
+/// Module that renders benchmark timings as an SVG chart and keeps a history of past runs.
+/// The chart is written by hand, so it does not need a plotting dependency.
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    fs,
+    io::{self, Write as _},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::template::config;
+use crate::Day;
+
+/// The default path of the chart, see `readme.chart` in `aoc.toml`.
+pub const CHART_PATH: &str = ".assets/benchmarks.svg";
+
+/// The history of past runs, `data/benchmarks/history.txt` by default.
+#[must_use]
+pub fn history_path() -> String {
+    config::project().paths.file("benchmarks", "history.txt")
+}
+
+/// The timings of a day in nanoseconds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayTimings {
+    pub day: Day,
+    pub part_1: Option<f64>,
+    pub part_2: Option<f64>,
+}
+
+impl DayTimings {
+    fn total(&self) -> f64 {
+        self.part_1.unwrap_or(0.0) + self.part_2.unwrap_or(0.0)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Appends a run to the history. Every line holds the timestamp of the run, the day
+/// and the nanoseconds of both parts, `-` for a part without timing.
+pub fn append_history(timings: &[DayTimings]) -> io::Result<()> {
+    let path = history_path();
+    if let Some(parent) = Path::new(&path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+
+    file.write_all(format_history(timestamp, timings).as_bytes())
+}
+
+/// Reads the total time of every day per run, oldest first.
+#[must_use]
+pub fn read_history() -> BTreeMap<Day, Vec<f64>> {
+    fs::read_to_string(history_path())
+        .map(|s| parse_history(&s))
+        .unwrap_or_default()
+}
+
+fn format_history(timestamp: u64, timings: &[DayTimings]) -> String {
+    let nanos = |n: Option<f64>| n.map_or("-".to_string(), |n| format!("{n:.0}"));
+
+    timings
+        .iter()
+        .map(|t| {
+            format!(
+                "{timestamp} {} {} {}\n",
+                t.day,
+                nanos(t.part_1),
+                nanos(t.part_2)
+            )
+        })
+        .collect()
+}
+
+fn parse_history(s: &str) -> BTreeMap<Day, Vec<f64>> {
+    let mut history: BTreeMap<Day, Vec<f64>> = BTreeMap::new();
+
+    for line in s.lines() {
+        let [_, day, part_1, part_2] = line.split_whitespace().collect::<Vec<_>>()[..] else {
+            continue;
+        };
+        let Ok(day) = day.parse() else {
+            continue;
+        };
+
+        let total: f64 = [part_1, part_2]
+            .iter()
+            .filter_map(|n| n.parse::<f64>().ok())
+            .sum();
+        history.entry(day).or_default().push(total);
+    }
+
+    history
+}
+
+/* -------------------------------------------------------------------------- */
+
+const WIDTH: f64 = 720.0;
+const LABEL_WIDTH: f64 = 70.0;
+const SPARKLINE_WIDTH: f64 = 100.0;
+const BAR_HEIGHT: f64 = 10.0;
+const ROW_HEIGHT: f64 = 28.0;
+const HEADER_HEIGHT: f64 = 40.0;
+const PART_COLORS: [&str; 2] = ["#f9c74f", "#43aa8b"];
+const MAX_HISTORY: usize = 20;
+
+/// Writes the chart to `readme.chart`, [`CHART_PATH`] by default.
+pub fn write(timings: &[DayTimings], history: &BTreeMap<Day, Vec<f64>>) -> io::Result<()> {
+    let path = config::resolve(&config::project().readme.chart);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, render(timings, history))
+}
+
+/// Renders a horizontal bar chart with a log-scale axis, one row per day and one bar per part.
+/// If a day has at least two runs in `history`, a sparkline of its total time is drawn next to it.
+#[must_use]
+pub fn render(timings: &[DayTimings], history: &BTreeMap<Day, Vec<f64>>) -> String {
+    let values = timings
+        .iter()
+        .flat_map(|t| [t.part_1, t.part_2])
+        .flatten()
+        .filter(|n| *n > 0.0);
+
+    let (min, max) = values.fold((f64::MAX, f64::MIN), |(min, max), n| {
+        (min.min(n), max.max(n))
+    });
+    let (min_exp, max_exp) = if min > max {
+        (0, 3)
+    } else {
+        let min_exp = min.log10().floor() as i32;
+        (min_exp, (max.log10().ceil() as i32).max(min_exp + 1))
+    };
+
+    let has_history = timings
+        .iter()
+        .any(|t| history.get(&t.day).is_some_and(|h| h.len() > 1));
+    let chart_end = if has_history {
+        WIDTH - SPARKLINE_WIDTH - 20.0
+    } else {
+        WIDTH - 10.0
+    };
+    let x = |nanos: f64| {
+        let ratio = (nanos.max(1.0).log10() - f64::from(min_exp)) / f64::from(max_exp - min_exp);
+        LABEL_WIDTH + ratio.clamp(0.0, 1.0) * (chart_end - LABEL_WIDTH)
+    };
+
+    #[allow(clippy::cast_precision_loss)]
+    let height = HEADER_HEIGHT + ROW_HEIGHT * timings.len() as f64 + 10.0;
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{height}" viewBox="0 0 {WIDTH} {height}" font-family="monospace" font-size="11">"#
+    );
+    svg.push_str(r##"<rect width="100%" height="100%" fill="#0f0f23"/>"##);
+
+    // axis with one gridline per power of ten.
+    for exp in min_exp..=max_exp {
+        let x = x(10_f64.powi(exp));
+        let _ = write!(
+            svg,
+            r##"<line x1="{x:.1}" y1="{}" x2="{x:.1}" y2="{}" stroke="#333340"/><text x="{x:.1}" y="{}" fill="#cccccc" text-anchor="middle">{}</text>"##,
+            HEADER_HEIGHT - 10.0,
+            height - 10.0,
+            HEADER_HEIGHT - 16.0,
+            format_nanos(10_f64.powi(exp))
+        );
+    }
+
+    for (part, color) in PART_COLORS.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let legend_x = LABEL_WIDTH + 80.0 * part as f64;
+        let _ = write!(
+            svg,
+            r##"<rect x="{legend_x}" y="6" width="10" height="10" fill="{color}"/><text x="{}" y="15" fill="#cccccc">Part {}</text>"##,
+            legend_x + 14.0,
+            part + 1
+        );
+    }
+
+    for (row, timing) in timings.iter().enumerate() {
+        #[allow(clippy::cast_precision_loss)]
+        let y = HEADER_HEIGHT + ROW_HEIGHT * row as f64;
+
+        let _ = write!(
+            svg,
+            r##"<text x="8" y="{:.1}" fill="#cccccc">Day {}</text>"##,
+            y + BAR_HEIGHT + 4.0,
+            timing.day
+        );
+
+        for (part, nanos) in [timing.part_1, timing.part_2].into_iter().enumerate() {
+            let Some(nanos) = nanos else {
+                continue;
+            };
+            #[allow(clippy::cast_precision_loss)]
+            let bar_y = y + BAR_HEIGHT * part as f64;
+            let _ = write!(
+                svg,
+                r##"<rect x="{LABEL_WIDTH}" y="{bar_y:.1}" width="{:.1}" height="{}" fill="{}"><title>Day {} part {}: {}</title></rect>"##,
+                (x(nanos) - LABEL_WIDTH).max(1.0),
+                BAR_HEIGHT - 1.0,
+                PART_COLORS[part],
+                timing.day,
+                part + 1,
+                format_nanos(nanos)
+            );
+        }
+
+        if let Some(points) = history.get(&timing.day).filter(|h| h.len() > 1) {
+            let points = &points[points.len().saturating_sub(MAX_HISTORY)..];
+            let _ = write!(
+                svg,
+                r##"<polyline points="{}" fill="none" stroke="#ffff66" stroke-width="1.5"><title>Day {} total: {}</title></polyline>"##,
+                sparkline(points, WIDTH - SPARKLINE_WIDTH - 10.0, y, 2.0 * BAR_HEIGHT),
+                timing.day,
+                format_nanos(timing.total())
+            );
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Lays out `values` in a box at `(x, y)`, log-scaled between their minimum and maximum.
+fn sparkline(values: &[f64], x: f64, y: f64, height: f64) -> String {
+    let logs: Vec<f64> = values.iter().map(|n| n.max(1.0).log10()).collect();
+    let min = logs.iter().copied().fold(f64::MAX, f64::min);
+    let max = logs.iter().copied().fold(f64::MIN, f64::max);
+    #[allow(clippy::cast_precision_loss)]
+    let step = SPARKLINE_WIDTH / (logs.len() - 1) as f64;
+
+    logs.iter()
+        .enumerate()
+        .map(|(i, log)| {
+            let ratio = if max > min {
+                (log - min) / (max - min)
+            } else {
+                0.5
+            };
+            #[allow(clippy::cast_precision_loss)]
+            let point_x = x + step * i as f64;
+            format!("{point_x:.1},{:.1}", y + height * (1.0 - ratio))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn format_nanos(nanos: f64) -> String {
+    match nanos {
+        n if n < 1e3 => format!("{n:.0}ns"),
+        n if n < 1e6 => format!("{}µs", trim_float(n / 1e3)),
+        n if n < 1e9 => format!("{}ms", trim_float(n / 1e6)),
+        n => format!("{}s", trim_float(n / 1e9)),
+    }
+}
+
+fn trim_float(n: f64) -> String {
+    let s = format!("{n:.1}");
+    s.strip_suffix(".0").map_or(s.clone(), str::to_string)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{format_history, format_nanos, parse_history, render, DayTimings};
+    use crate::day;
+
+    fn get_mock_timings() -> Vec<DayTimings> {
+        vec![
+            DayTimings {
+                day: day!(1),
+                part_1: Some(30_300.0),
+                part_2: Some(98_000.0),
+            },
+            DayTimings {
+                day: day!(3),
+                part_1: Some(2_500_000.0),
+                part_2: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn roundtrips_history() {
+        let mut s = format_history(1_700_000_000, &get_mock_timings());
+        s.push_str(&format_history(1_700_000_100, &get_mock_timings()[..1]));
+
+        assert_eq!(s.lines().next(), Some("1700000000 01 30300 98000"));
+
+        let history = parse_history(&s);
+        assert_eq!(history[&day!(1)], vec![128_300.0, 128_300.0]);
+        assert_eq!(history[&day!(3)], vec![2_500_000.0]);
+    }
+
+    #[test]
+    fn renders_bars_on_log_scale() {
+        let svg = render(&get_mock_timings(), &BTreeMap::new());
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("</title></rect>").count(), 3);
+        // the axis spans the powers of ten around all timings.
+        assert!(svg.contains(">10µs</text>"));
+        assert!(svg.contains(">10ms</text>"));
+        assert!(!svg.contains(">1µs</text>"));
+        assert!(!svg.contains("<polyline"));
+    }
+
+    #[test]
+    fn renders_sparklines() {
+        let mut history = BTreeMap::new();
+        history.insert(day!(1), vec![200_000.0, 150_000.0, 128_300.0]);
+        history.insert(day!(3), vec![2_500_000.0]);
+
+        let svg = render(&get_mock_timings(), &history);
+        assert_eq!(svg.matches("<polyline").count(), 1);
+    }
+
+    #[test]
+    fn renders_without_timings() {
+        let svg = render(&[], &BTreeMap::new());
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn formats_nanos() {
+        assert_eq!(format_nanos(10.0), "10ns");
+        assert_eq!(format_nanos(30_300.0), "30.3µs");
+        assert_eq!(format_nanos(1e6), "1ms");
+        assert_eq!(format_nanos(2.5e9), "2.5s");
+    }
+}
@@ -1,31 +1,142 @@
-use std::io;
+use std::{
+    collections::BTreeMap,
+    io, process,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
 
 use crate::template::{
+    bench::BenchOptions,
+    benchmark_chart::{self, DayTimings},
+    commands::{gen_input, scaffold},
+    config, generate,
     readme_benchmarks::{self, Timings},
     ANSI_BOLD, ANSI_ITALIC, ANSI_RESET,
 };
-use crate::{all_days, Day};
+use crate::{Day, DaySet};
+
+/// The options of `cargo all`.
+pub struct Options {
+    pub release: bool,
+    pub time: bool,
+    pub chart: bool,
+    pub count_allocations: bool,
+    pub threads: Option<usize>,
+    /// Run each part this many times and report whether the answers agree instead of timing it.
+    pub check_determinism: Option<usize>,
+    pub scales: Option<Vec<usize>>,
+    /// The days to run, i.e. `--days` without `--skip`.
+    pub days: DaySet,
+    /// How many solutions run at the same time.
+    pub jobs: usize,
+    pub allow_noisy_timings: bool,
+    pub bench: BenchOptions,
+}
+
+pub fn handle(options: Options) {
+    let Options {
+        release: is_release,
+        time: is_timed,
+        chart: is_charted,
+        count_allocations,
+        threads,
+        check_determinism,
+        scales,
+        days,
+        jobs,
+        allow_noisy_timings,
+        bench,
+    } = options;
+
+    if jobs > 1 && is_timed && !allow_noisy_timings {
+        eprintln!("Solutions running concurrently compete for the CPU, which makes their timings unreliable.");
+        eprintln!("Run with `--jobs 1` or pass `--allow-noisy-timings` to time them anyway.");
+        process::exit(1);
+    }
+
+    // arguments that are passed on to the solutions as-is.
+    let mut forwarded = bench.to_args();
+    if let Some(threads) = threads {
+        forwarded.extend(["--threads".into(), threads.to_string()]);
+    }
+
+    if let Some(scales) = scales {
+        handle_scaling(is_release, count_allocations, &forwarded, &days, &scales);
+        return;
+    }
+
+    if let Some(runs) = check_determinism {
+        forwarded.extend(["--check-determinism".into(), runs.to_string()]);
+    }
 
-pub fn handle(is_release: bool, is_timed: bool) {
     let mut timings: Vec<Timings> = vec![];
+    // the verdicts of `--check-determinism` per part, in the same order as `timings`.
+    let mut determinism: Vec<[Option<bool>; 2]> = vec![];
 
-    all_days().for_each(|day| {
-        if day > 1 {
+    let print_header = |i: usize, day: Day| {
+        if i > 0 {
             println!();
         }
 
         println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
         println!("------");
+    };
 
-        let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
-
+    let mut record = |day: Day, output: Vec<String>| {
         if output.is_empty() {
             println!("Not solved.");
         } else {
-            let val = child_commands::parse_exec_time(&output, day);
-            timings.push(val);
+            timings.push(child_commands::parse_exec_time(&output, day));
+            determinism.push(child_commands::parse_determinism(&output));
         }
-    });
+    };
+
+    if jobs > 1 {
+        // build once up front, concurrent `cargo run`s would wait on each other's build lock.
+        child_commands::build(is_release, count_allocations);
+
+        let days: Vec<Day> = days.iter().collect();
+        let run = |day| {
+            child_commands::run_solution_buffered(
+                day,
+                is_timed,
+                is_release,
+                count_allocations,
+                &forwarded,
+            )
+            .unwrap()
+        };
+
+        run_in_order(&days, jobs, run, |i, day, (stdout, stderr)| {
+            print_header(i, day);
+            stdout.iter().for_each(|line| println!("{line}"));
+            eprint!("{stderr}");
+            record(day, stdout);
+        });
+    } else {
+        days.iter().enumerate().for_each(|(i, day)| {
+            print_header(i, day);
+
+            let output = child_commands::run_solution(
+                day,
+                is_timed,
+                is_release,
+                count_allocations,
+                &forwarded,
+                None,
+            )
+            .unwrap();
+
+            record(day, output);
+        });
+    }
+
+    if is_timed || check_determinism.is_some() {
+        print_results(&timings, check_determinism.map(|_| determinism.as_slice()));
+    }
 
     if is_timed {
         let total_millis = timings.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
@@ -33,7 +144,12 @@ pub fn handle(is_release: bool, is_timed: bool) {
         println!("\n{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}");
 
         if is_release {
-            match readme_benchmarks::update(timings, total_millis) {
+            // days that were not run keep their rows in the readme.
+            let merged = readme_benchmarks::merge(timings.clone(), &days);
+            let total_millis = merged.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64;
+            let chart = is_charted.then(|| write_chart(&timings, &merged));
+
+            match readme_benchmarks::update(merged, total_millis, chart.flatten()) {
                 Ok(()) => println!("Successfully updated README with benchmarks."),
                 Err(_) => {
                     eprintln!("Failed to update readme with benchmarks.");
@@ -43,6 +159,213 @@ pub fn handle(is_release: bool, is_timed: bool) {
     }
 }
 
+/// Prints a table of the timings of every day that was run, with a column for
+/// the verdicts of `--check-determinism` if `determinism` is set.
+fn print_results(timings: &[Timings], determinism: Option<&[[Option<bool>; 2]]>) {
+    let timing = |timing: &Option<String>| timing.clone().unwrap_or("-".into());
+    let verdict = |[part_1, part_2]: [Option<bool>; 2]| match (part_1, part_2) {
+        (Some(false), Some(false)) => "✖ parts 1 and 2",
+        (Some(false), _) => "✖ part 1",
+        (_, Some(false)) => "✖ part 2",
+        (None, None) => "-",
+        _ => "✔",
+    };
+
+    println!("\n{ANSI_BOLD}Results{ANSI_RESET}");
+
+    if determinism.is_some() {
+        println!("| Day | Part 1 | Part 2 | Deterministic |");
+    } else {
+        println!("| Day | Part 1 | Part 2 |");
+    }
+
+    for (i, t) in timings.iter().enumerate() {
+        let mut line = format!(
+            "| {} | {} | {} |",
+            t.day,
+            timing(&t.part_1),
+            timing(&t.part_2)
+        );
+        if let Some(determinism) = determinism {
+            line.push_str(&format!(" {} |", verdict(determinism[i])));
+        }
+        println!("{line}");
+    }
+}
+
+/// Runs `run` for every day on up to `jobs` threads and hands the results to `emit` in day order,
+/// as soon as all earlier days are done.
+fn run_in_order<T: Send>(
+    days: &[Day],
+    jobs: usize,
+    run: impl Fn(Day) -> T + Sync,
+    mut emit: impl FnMut(usize, Day, T),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(days.len()) {
+            let sender = sender.clone();
+            let (next, run) = (&next, &run);
+
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&day) = days.get(i) else {
+                    break;
+                };
+                if sender.send((i, run(day))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut emitted = 0;
+
+        for (i, result) in receiver {
+            pending.insert(i, result);
+
+            while let Some(result) = pending.remove(&emitted) {
+                emit(emitted, days[emitted], result);
+                emitted += 1;
+            }
+        }
+    });
+}
+
+/// Records the timings of this run in the benchmark history and renders the chart of all timings.
+/// Returns the path of the chart if it was written.
+fn write_chart(timings: &[Timings], all_timings: &[Timings]) -> Option<&'static str> {
+    let parse = |timing: &Option<String>| readme_benchmarks::parse_duration(timing.as_ref()?);
+    let to_chart = |timings: &[Timings]| -> Vec<DayTimings> {
+        timings
+            .iter()
+            .map(|t| DayTimings {
+                day: t.day,
+                part_1: parse(&t.part_1),
+                part_2: parse(&t.part_2),
+            })
+            .collect()
+    };
+
+    if let Err(e) = benchmark_chart::append_history(&to_chart(timings)) {
+        eprintln!("Failed to record benchmark history: {e}");
+    }
+
+    match benchmark_chart::write(&to_chart(all_timings), &benchmark_chart::read_history()) {
+        Ok(()) => {
+            println!("Successfully wrote benchmark chart.");
+            Some(config::project().readme.chart.as_str())
+        }
+        Err(e) => {
+            eprintln!("Failed to write benchmark chart: {e}");
+            None
+        }
+    }
+}
+
+/// Benchmarks every day that has an input generator on generated inputs of increasing size
+/// and prints how the timings grow with the input.
+fn handle_scaling(
+    is_release: bool,
+    count_allocations: bool,
+    forwarded: &[String],
+    days: &DaySet,
+    scales: &[usize],
+) {
+    let mut scales = scales.to_vec();
+    scales.sort_unstable();
+    scales.dedup();
+
+    let mut results: Vec<(Day, Vec<(usize, Timings)>)> = vec![];
+
+    days.iter().for_each(|day| {
+        let Some(generator) = generate::generator(day) else {
+            return;
+        };
+        if !has_bin(day) {
+            return;
+        }
+        if !generator.scales() {
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET} {ANSI_ITALIC}skipped, its input does not grow with the scale{ANSI_RESET}");
+            println!();
+            return;
+        }
+
+        let mut day_results = vec![];
+
+        for &scale in &scales {
+            println!("{ANSI_BOLD}Day {day}{ANSI_RESET} {ANSI_ITALIC}@ scale {scale}{ANSI_RESET}");
+            println!("------");
+
+            let path = match gen_input::write_input(day, scale, generate::DEFAULT_SEED) {
+                Ok(path) => path,
+                Err(e) => {
+                    eprintln!("Failed to generate input: {e}");
+                    continue;
+                }
+            };
+
+            let output = child_commands::run_solution(
+                day,
+                true,
+                is_release,
+                count_allocations,
+                forwarded,
+                Some(&path),
+            )
+            .unwrap();
+            day_results.push((scale, child_commands::parse_exec_time(&output, day)));
+            println!();
+        }
+
+        results.push((day, day_results));
+    });
+
+    println!("{ANSI_BOLD}Growth{ANSI_RESET}");
+    println!("------");
+
+    for (day, day_results) in results {
+        println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
+        println!("| Scale | Part 1 | Part 2 |");
+
+        let mut prev: Option<&(usize, Timings)> = None;
+
+        for current in &day_results {
+            let (scale, timings) = current;
+            let cell = |part: fn(&Timings) -> &Option<String>| {
+                let Some(timing) = part(timings) else {
+                    return "-".to_string();
+                };
+                let growth = prev.and_then(|(prev_scale, prev_timings)| {
+                    let prev_nanos =
+                        readme_benchmarks::parse_duration(part(prev_timings).as_ref()?)?;
+                    let nanos = readme_benchmarks::parse_duration(timing)?;
+                    #[allow(clippy::cast_precision_loss)]
+                    let ratio = *scale as f64 / *prev_scale as f64;
+                    Some((nanos / prev_nanos).ln() / ratio.ln())
+                });
+                match growth {
+                    Some(exp) => format!("{timing} (n^{exp:.2})"),
+                    None => timing.clone(),
+                }
+            };
+
+            println!(
+                "| x{scale} | {} | {} |",
+                cell(|t| &t.part_1),
+                cell(|t| &t.part_2)
+            );
+            prev = Some(current);
+        }
+
+        println!();
+    }
+}
+
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
@@ -56,43 +379,137 @@ impl From<std::io::Error> for Error {
     }
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+/// Whether the day has a solution binary.
+fn has_bin(day: Day) -> bool {
+    config::resolve(scaffold::get_module_path(day)).exists()
 }
 
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 mod child_commands {
-    use super::{get_path_for_bin, Error};
+    use super::{has_bin, Error};
+    use crate::template::{alloc, readme_benchmarks::parse_duration};
     use crate::Day;
     use std::{
         io::{BufRead, BufReader},
-        path::Path,
         process::{Command, Stdio},
         thread,
     };
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+    /// The `cargo` arguments to run the solution bin for a given day, `forwarded` is passed to the bin as-is.
+    fn solution_args(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        count_allocations: bool,
+        forwarded: &[String],
+        input: Option<&str>,
+    ) -> Vec<String> {
+        let mut args: Vec<String> = vec![
+            "run".into(),
+            "--quiet".into(),
+            "--bin".into(),
+            day.to_string(),
+        ];
+
+        if is_release {
+            args.push("--release".into());
+        }
+
+        if count_allocations {
+            args.push("--features".into());
+            args.push("count_allocations".into());
+        }
+
+        args.push("--".into());
+
+        if is_timed {
+            // mirror `--time` flag to child invocations.
+            args.push("--time".into());
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        if let Some(input) = input {
+            args.push("--input".into());
+            args.push(input.into());
+        }
+
+        args.extend_from_slice(forwarded);
+
+        args
+    }
+
+    /// Build all solution bins, so that concurrent runs do not each wait for the build.
+    pub fn build(is_release: bool, count_allocations: bool) {
+        let mut args = vec!["build", "--quiet", "--bins"];
 
         if is_release {
             args.push("--release");
         }
 
-        if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
-            args.push("--time");
+        if count_allocations {
+            args.push("--features");
+            args.push("count_allocations");
         }
 
+        // a failing build is reported again by every run, no need to handle it here.
+        let _ = Command::new("cargo").args(&args).status();
+    }
+
+    /// Run the solution bin for a given day and return its stdout lines and stderr
+    /// instead of forwarding them.
+    pub fn run_solution_buffered(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        count_allocations: bool,
+        forwarded: &[String],
+    ) -> Result<(Vec<String>, String), Error> {
+        if !has_bin(day) {
+            return Ok((vec![], String::new()));
+        }
+
+        let args = solution_args(
+            day,
+            is_timed,
+            is_release,
+            count_allocations,
+            forwarded,
+            None,
+        );
+        let output = Command::new("cargo").args(&args).output()?;
+
+        Ok((
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(String::from)
+                .collect(),
+            String::from_utf8_lossy(&output.stderr).into_owned(),
+        ))
+    }
+
+    /// Run the solution bin for a given day, optionally on an input file other than the puzzle input.
+    pub fn run_solution(
+        day: Day,
+        is_timed: bool,
+        is_release: bool,
+        count_allocations: bool,
+        forwarded: &[String],
+        input: Option<&str>,
+    ) -> Result<Vec<String>, Error> {
+        // skip command invocation for days that have not been scaffolded yet.
+        if !has_bin(day) {
+            return Ok(vec![]);
+        }
+
+        let args = solution_args(
+            day,
+            is_timed,
+            is_release,
+            count_allocations,
+            forwarded,
+            input,
+        );
+
         // spawn child command with piped stdout/stderr.
         // forward output to stdout/stderr while grabbing stdout lines.
 
@@ -131,8 +548,15 @@ mod child_commands {
             part_1: None,
             part_2: None,
             total_nanos: 0_f64,
+            peak_memory: None,
         };
 
+        timings.peak_memory = output
+            .iter()
+            .filter(|l| l.contains(" samples)"))
+            .filter_map(|l| parse_peak_memory(l))
+            .max();
+
         output
             .iter()
             .filter_map(|l| {
@@ -161,33 +585,44 @@ mod child_commands {
         timings
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Reads the verdicts of `--check-determinism` per part, `None` if a part was not checked.
+    pub fn parse_determinism(output: &[String]) -> [Option<bool>; 2] {
+        let verdict = |part: &str| {
+            let line = output.iter().find(|l| l.starts_with(part))?;
+            if line.contains(" nondeterministic") {
+                Some(false)
+            } else if line.contains(" deterministic") {
+                Some(true)
+            } else {
+                None
+            }
+        };
+
+        [verdict("Part 1:"), verdict("Part 2:")]
+    }
+
+    /// Parses the peak from allocation stats like `[3 allocs, 1.5 KiB allocated, peak 1.0 KiB]`.
+    fn parse_peak_memory(line: &str) -> Option<u64> {
+        let stats = line.rsplit_once(" [")?.1;
+        let peak = stats.split("peak ").nth(1)?.strip_suffix(']')?;
+        alloc::parse_bytes(peak)
     }
 
     fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
         let str_timing = line
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
 
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+        Some((str_timing, parse_duration(str_timing)?))
     }
 
     /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
+    #[cfg(all(test, feature = "test_lib"))]
     macro_rules! assert_approx_eq {
         ($a:expr, $b:expr) => {{
             let (a, b) = (&$a, &$b);
@@ -200,9 +635,9 @@ mod child_commands {
         }};
     }
 
-    #[cfg(feature = "test_lib")]
+    #[cfg(all(test, feature = "test_lib"))]
     mod tests {
-        use super::parse_exec_time;
+        use super::{parse_determinism, parse_exec_time};
 
         use crate::day;
 
@@ -236,6 +671,33 @@ mod child_commands {
             assert_eq!(res.part_2.unwrap(), "100ms");
         }
 
+        #[test]
+        fn test_alloc_stats() {
+            let res = parse_exec_time(
+                &[
+                    "Part 1: 0 (74.13ns @ 100000 samples) [cpu 80.0ns, 1.08x parallel] [3 allocs, 2.0 KiB allocated, peak 1.5 KiB]".into(),
+                    "Part 2: 10 (74.13ms @ 99999 samples) [1 allocs, 10 B allocated, peak 10 B]".into(),
+                    "".into(),
+                ],
+                day!(1),
+            );
+            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
+            assert_eq!(res.part_2.unwrap(), "74.13ms");
+            assert_eq!(res.peak_memory, Some(1536));
+        }
+
+        #[test]
+        fn test_determinism() {
+            let res = parse_determinism(&[
+                "Part 1: ✔ deterministic over 5 runs".into(),
+                "Part 2: ✖ nondeterministic, 2 distinct answers over 5 runs".into(),
+                "  42 (runs 1, 3, 5)".into(),
+                "  41 (runs 2, 4)".into(),
+            ]);
+            assert_eq!(res, [Some(true), Some(false)]);
+            assert_eq!(parse_determinism(&["Part 1: ✖".into()]), [None, None]);
+        }
+
         #[test]
         fn test_missing_parts() {
             let res = parse_exec_time(
@@ -247,8 +709,35 @@ mod child_commands {
                 day!(1),
             );
             assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
+            assert!(res.part_1.is_none());
+            assert!(res.part_2.is_none());
         }
     }
 }
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::run_in_order;
+    use crate::{all_days, Day};
+    use std::{thread, time::Duration};
+
+    #[test]
+    fn runs_in_day_order() {
+        let days: Vec<Day> = all_days().collect();
+        let mut emitted = vec![];
+
+        // earlier days finish last, so results arrive out of order.
+        let run = |day: Day| {
+            thread::sleep(Duration::from_millis(u64::from(26 - day.into_inner())));
+            day.into_inner()
+        };
+
+        run_in_order(&days, 4, run, |i, day, result| {
+            assert_eq!(day.into_inner(), result);
+            emitted.push((i, result));
+        });
+
+        let expected: Vec<(usize, u8)> = (0..25).map(|i| (i, i as u8 + 1)).collect();
+        assert_eq!(emitted, expected);
+    }
+}
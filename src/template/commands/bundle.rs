@@ -0,0 +1,59 @@
+/// Builds the `bundle` binary, which contains every solution and embeds its input and examples.
+use std::{fs, process, process::Command};
+
+use crate::template::{commands::scaffold, config, input::Input};
+use crate::{all_days, Day};
+
+/// Where cargo writes the binary.
+const BUNDLE_PATH: &str = "target/release/bundle";
+
+pub fn handle(out: Option<String>) {
+    let status = Command::new("cargo")
+        .args([
+            "build",
+            "--quiet",
+            "--release",
+            "--features",
+            "embed_inputs",
+            "--bin",
+            "bundle",
+        ])
+        .status();
+
+    if !status.is_ok_and(|status| status.success()) {
+        eprintln!("Failed to build the bundle.");
+        process::exit(1);
+    }
+
+    let path = match out {
+        Some(out) => {
+            if let Err(e) = fs::copy(config::resolve(BUNDLE_PATH), &out) {
+                eprintln!("Failed to copy the bundle to \"{out}\": {e}");
+                process::exit(1);
+            }
+            out
+        }
+        None => config::resolve(BUNDLE_PATH).to_string_lossy().into_owned(),
+    };
+
+    let days: Vec<Day> = all_days()
+        .filter(|day| config::resolve(scaffold::get_module_path(*day)).exists())
+        .collect();
+    let with_input: Vec<String> = days
+        .iter()
+        .filter(|day| Input::load("inputs", **day).is_ok())
+        .map(ToString::to_string)
+        .collect();
+
+    println!("Bundled {} days into \"{path}\".", days.len());
+    println!("Embedded inputs: {}", list(&with_input));
+    println!("Run a day with `{path} <day>` or every day with an input with `{path} all`.");
+}
+
+fn list(days: &[String]) -> String {
+    if days.is_empty() {
+        "none".into()
+    } else {
+        days.join(", ")
+    }
+}
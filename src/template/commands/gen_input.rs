@@ -0,0 +1,45 @@
+use std::{fs, path::Path, process};
+
+use crate::template::{config, generate};
+use crate::Day;
+
+#[must_use]
+pub fn get_generated_path(day: Day, scale: usize) -> String {
+    config::project()
+        .paths
+        .file("generated", &format!("{day}-x{scale}.txt"))
+}
+
+/// Generates an input for `day` and writes it to `data/generated`.
+/// Returns the path of the written file.
+pub fn write_input(day: Day, scale: usize, seed: u64) -> Result<String, String> {
+    let input = generate::generate(day, scale, seed)
+        .ok_or_else(|| format!("day {day} does not have an input generator."))?;
+
+    let path = get_generated_path(day, scale);
+    if let Some(parent) = Path::new(&path).parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(&path, input).map_err(|e| e.to_string())?;
+
+    Ok(path)
+}
+
+pub fn handle(day: Day, scale: usize, seed: Option<u64>) {
+    let seed = seed.unwrap_or(generate::DEFAULT_SEED);
+
+    match write_input(day, scale, seed) {
+        Ok(path) => {
+            println!("Generated input \"{path}\" (scale {scale}, seed {seed}).");
+            if scale > 1 && generate::generator(day).is_some_and(|g| !g.scales()) {
+                println!("Note: the input of day {day} does not grow with the scale.");
+            }
+            println!("---");
+            println!("🎄 Type `cargo solve {day} --input {path}` to run your solution on it.");
+        }
+        Err(e) => {
+            eprintln!("Failed to generate input: {e}");
+            process::exit(1);
+        }
+    }
+}
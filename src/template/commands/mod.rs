@@ -1,5 +1,10 @@
 pub mod all;
+pub mod bundle;
 pub mod download;
+pub mod gen_input;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
+pub mod stars;
+pub mod status;
+pub mod verify_builds;
@@ -1,9 +1,11 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
     process,
 };
 
+use crate::template::aoc_cli;
+use crate::template::config::{self, ScaffoldTemplate};
 use crate::Day;
 
 const MODULE_TEMPLATE: &str = r#"advent_of_code::solution!(DAY_NUMBER);
@@ -34,20 +36,144 @@ mod tests {
 }
 "#;
 
+/// The module of a solution in `src/solutions`, created by `cargo scaffold <day> --lib`.
+const LIB_MODULE_TEMPLATE: &str = r#"use crate::{Answer, Day, Solution};
+
+/// The current day.
+pub const DAY: Day = crate::day!(DAY_NUMBER);
+
+pub fn part_one(input: &str) -> Option<u32> {
+    None
+}
+
+pub fn part_two(input: &str) -> Option<u32> {
+    None
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: Day = DAY;
+    const NAME: &'static str = "";
+
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Self::Input<'_> {
+        input
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Option<Answer> {
+        part_one(input).map(Answer::from)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Option<Answer> {
+        part_two(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, None);
+    }
+}
+"#;
+
+/// The binary of a solution in `src/solutions`, which only runs the module.
+const LIB_BIN_TEMPLATE: &str = r#"use advent_of_code::solutions::dayDAY_PADDED::*;
+
+advent_of_code::solution!(DAY_NUMBER);
+"#;
+
+const SOLUTIONS_PATH: &str = "src/solutions/mod.rs";
+
+/// Creates a file relative to the root of the crate, failing if it exists.
 fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(config::resolve(path))
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
-pub fn handle(day: Day) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+#[must_use]
+pub fn get_module_path(day: Day) -> String {
+    format!("src/bin/{day}.rs")
+}
+
+#[must_use]
+pub fn get_lib_module_path(day: Day) -> String {
+    format!("src/solutions/day{day}.rs")
+}
 
-    let mut file = match safe_create_file(&module_path) {
+#[must_use]
+pub fn get_example_path(day: Day) -> String {
+    config::project()
+        .paths
+        .file("examples", &format!("{day}.txt"))
+}
+
+/// The module template set in `aoc.toml`, or the default one.
+fn module_template() -> Result<String, std::io::Error> {
+    match &config::project().scaffold_template {
+        ScaffoldTemplate::Default => Ok(MODULE_TEMPLATE.to_string()),
+        ScaffoldTemplate::File(path) => fs::read_to_string(path),
+    }
+}
+
+/// Adds the module of a day to the `pub mod` lines and the solver list of `src/solutions/mod.rs`.
+/// Returns `None` if the file does not have these lines.
+fn register_module(contents: &str, day: Day) -> Option<String> {
+    let mut lines: Vec<String> = contents.lines().map(String::from).collect();
+
+    let entries = [
+        (format!("pub mod day{day};"), "pub mod day"),
+        (
+            format!("    ({}, day{day}::Solver::solve),", day.into_inner()),
+            "    (",
+        ),
+    ];
+
+    for (entry, prefix) in entries {
+        let is_entry = |line: &String| line.starts_with(prefix) && line.contains("day");
+        let first = lines.iter().position(is_entry)?;
+        let last = lines.iter().rposition(is_entry)?;
+
+        let index = (first..=last)
+            .find(|i| day_of_line(&lines[*i]) > Some(day.into_inner()))
+            .unwrap_or(last + 1);
+        lines.insert(index, entry);
+    }
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// The day of a `pub mod dayDD;` line or an entry of the solver list.
+fn day_of_line(line: &str) -> Option<u8> {
+    let (_, rest) = line.split_once("day")?;
+    rest.get(..2)?.parse().ok()
+}
+
+/// Writes a new file, exiting if it already exists.
+fn write_new_file(path: &str, contents: &str) {
+    let mut file = match safe_create_file(path) {
         Ok(file) => file,
         Err(e) => {
             eprintln!("Failed to create module file: {e}");
@@ -55,19 +181,76 @@ pub fn handle(day: Day) {
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("DAY_NUMBER", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
+    match file.write_all(contents.as_bytes()) {
         Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{path}\"");
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {e}");
             process::exit(1);
         }
     }
+}
+
+/// Creates the solution as a module in `src/solutions` with a binary that runs it.
+fn scaffold_lib(day: Day) {
+    let solutions = fs::read_to_string(config::resolve(SOLUTIONS_PATH))
+        .ok()
+        .and_then(|contents| register_module(&contents, day));
+
+    let Some(solutions) = solutions else {
+        eprintln!("Failed to add the module to \"{SOLUTIONS_PATH}\", it needs at least one `pub mod dayDD;` line and solver entry.");
+        process::exit(1);
+    };
+
+    let (module_path, bin_path) = (get_lib_module_path(day), get_module_path(day));
+
+    // check both files first, so a failure does not leave an unregistered module behind.
+    for path in [&module_path, &bin_path] {
+        if config::resolve(path).exists() {
+            eprintln!("Failed to create module file: \"{path}\" already exists");
+            process::exit(1);
+        }
+    }
+
+    let fill = |template: &str| {
+        template
+            .replace("DAY_NUMBER", &day.into_inner().to_string())
+            .replace("DAY_PADDED", &day.to_string())
+    };
+
+    write_new_file(&module_path, &fill(LIB_MODULE_TEMPLATE));
+    write_new_file(&bin_path, &fill(LIB_BIN_TEMPLATE));
+
+    if let Err(e) = fs::write(config::resolve(SOLUTIONS_PATH), solutions) {
+        eprintln!("Failed to add the module to \"{SOLUTIONS_PATH}\": {e}");
+        process::exit(1);
+    }
+    println!("Registered module in \"{SOLUTIONS_PATH}\"");
+}
+
+/// Creates the solution of a day and empty input and example files.
+/// With `lib`, the solution is a module in `src/solutions`, see [`crate::solutions`].
+pub fn handle(day: Day, lib: bool) {
+    let input_path = aoc_cli::get_input_path(day);
+    let example_path = get_example_path(day);
+
+    if lib {
+        scaffold_lib(day);
+    } else {
+        let template = match module_template() {
+            Ok(template) => template,
+            Err(e) => {
+                eprintln!("Failed to read module template: {e}");
+                process::exit(1);
+            }
+        };
+
+        write_new_file(
+            &get_module_path(day),
+            &template.replace("DAY_NUMBER", &day.into_inner().to_string()),
+        );
+    }
 
     match create_file(&input_path) {
         Ok(_) => {
@@ -92,3 +275,23 @@ pub fn handle(day: Day) {
     println!("---");
     println!("🎄 Type `cargo solve {}` to run your solution.", day);
 }
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::register_module;
+    use crate::day;
+
+    #[test]
+    fn registers_module_in_order() {
+        let contents = "use crate::Day;\n\npub mod day01;\npub mod day09;\n\nconst SOLUTIONS: &[(u8, Solve)] = &[\n    (1, day01::Solver::solve),\n    (9, day09::Solver::solve),\n];\n";
+
+        assert_eq!(
+            register_module(contents, day!(5)).unwrap(),
+            "use crate::Day;\n\npub mod day01;\npub mod day05;\npub mod day09;\n\nconst SOLUTIONS: &[(u8, Solve)] = &[\n    (1, day01::Solver::solve),\n    (5, day05::Solver::solve),\n    (9, day09::Solver::solve),\n];\n"
+        );
+        assert!(register_module(contents, day!(12))
+            .unwrap()
+            .contains("pub mod day09;\npub mod day12;\n"));
+        assert_eq!(register_module("", day!(5)), None);
+    }
+}
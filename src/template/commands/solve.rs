@@ -1,14 +1,65 @@
 use std::process::{Command, Stdio};
 
-use crate::Day;
+use crate::template::bench::BenchOptions;
+use crate::{Day, Part};
+
+/// The options of `cargo solve`. Everything but `release` and `count_allocations`
+/// is forwarded to the solution binary.
+#[derive(Default)]
+pub struct Options {
+    pub release: bool,
+    pub time: bool,
+    pub count_allocations: bool,
+    pub threads: Option<usize>,
+    pub scaling: bool,
+    pub profile: bool,
+    pub check_determinism: Option<usize>,
+    pub submit_part: Option<Part>,
+    pub wait: bool,
+    pub variant: Option<String>,
+    /// A file to read instead of `data/inputs/DD.txt`, e.g. a generated input.
+    pub input: Option<String>,
+    /// The number of `-v` flags, see [`crate::aoc_debug`].
+    pub verbosity: u8,
+    pub bench: BenchOptions,
+}
+
+pub fn handle(day: Day, options: Options) {
+    let Options {
+        release,
+        time,
+        count_allocations,
+        threads,
+        scaling,
+        profile,
+        check_determinism,
+        submit_part,
+        wait,
+        variant,
+        input,
+        verbosity,
+        bench,
+    } = options;
 
-pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
     let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
 
     if release {
         cmd_args.push("--release".to_string());
     }
 
+    let features: Vec<&str> = [
+        (count_allocations, "count_allocations"),
+        (profile, "profile"),
+    ]
+    .into_iter()
+    .filter_map(|(enabled, feature)| enabled.then_some(feature))
+    .collect();
+
+    if !features.is_empty() {
+        cmd_args.push("--features".to_string());
+        cmd_args.push(features.join(","));
+    }
+
     cmd_args.push("--".to_string());
 
     if let Some(submit_part) = submit_part {
@@ -16,10 +67,48 @@ pub fn handle(day: Day, release: bool, time: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
+    if wait {
+        cmd_args.push("--wait".to_string());
+    }
+
     if time {
         cmd_args.push("--time".to_string());
     }
 
+    if let Some(threads) = threads {
+        cmd_args.push("--threads".to_string());
+        cmd_args.push(threads.to_string());
+    }
+
+    if scaling {
+        cmd_args.push("--scaling".to_string());
+    }
+
+    if profile {
+        cmd_args.push("--profile".to_string());
+    }
+
+    if let Some(runs) = check_determinism {
+        cmd_args.push("--check-determinism".to_string());
+        cmd_args.push(runs.to_string());
+    }
+
+    if let Some(variant) = variant {
+        cmd_args.push("--variant".to_string());
+        cmd_args.push(variant);
+    }
+
+    if let Some(input) = input {
+        cmd_args.push("--input".to_string());
+        cmd_args.push(input);
+    }
+
+    cmd_args.extend(bench.to_args());
+
+    if verbosity > 0 {
+        cmd_args.push(format!("-{}", "v".repeat(verbosity.into())));
+    }
+
     let mut cmd = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
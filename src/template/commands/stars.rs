@@ -0,0 +1,67 @@
+use std::{fs, process};
+
+use crate::template::{
+    answers, aoc_cli,
+    readme_benchmarks::{self, Stars},
+};
+use crate::{all_days, Day, Part};
+
+pub fn handle() {
+    if let Err(e) = update() {
+        eprintln!("Failed to update readme with stars: {e}");
+        process::exit(1);
+    }
+
+    println!("Successfully updated README with stars.");
+}
+
+/// Regenerates the stars table in the readme for the year set in `aoc.toml`.
+pub fn update() -> Result<(), String> {
+    let year = aoc_cli::get_year().ok_or("the year is not set, add `year` to aoc.toml")?;
+    let stars = all_days().map(collect).collect();
+    readme_benchmarks::update_stars(stars, year).map_err(|e| format!("{e:?}"))
+}
+
+/// A part is solved if its correct answer is stored or the puzzle description shows it.
+fn collect(day: Day) -> Stars {
+    let puzzle = fs::read_to_string(aoc_cli::get_puzzle_path(day)).unwrap_or_default();
+    let solved_in_puzzle = count_solved_parts(&puzzle);
+
+    Stars {
+        day,
+        part_1: answers::read(day, Part::One).is_some() || solved_in_puzzle >= 1,
+        part_2: answers::read(day, Part::Two).is_some() || solved_in_puzzle >= 2,
+    }
+}
+
+/// The puzzle description lists the answer below each solved part. The puzzle of the last day
+/// only has one answer, its second star is awarded once all other puzzles are solved.
+fn count_solved_parts(puzzle: &str) -> usize {
+    if puzzle.contains("Both parts of this puzzle are complete!") {
+        return 2;
+    }
+
+    puzzle.matches("Your puzzle answer was").count()
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::count_solved_parts;
+
+    #[test]
+    fn counts_solved_parts() {
+        assert_eq!(count_solved_parts("## --- Day 1: Trebuchet?! ---"), 0);
+        assert_eq!(
+            count_solved_parts(
+                "## --- Day 1 ---\n\nYour puzzle answer was `142`.\n\n## --- Part Two ---\n\nYour puzzle answer was `281`.\n\nBoth parts of this puzzle are complete!"
+            ),
+            2
+        );
+        assert_eq!(
+            count_solved_parts(
+                "## --- Day 25: Snowverload ---\n\nYour puzzle answer was `543256`.\n\n## --- Part Two ---\n\nBoth parts of this puzzle are complete! They provide two gold stars: \\*\\*"
+            ),
+            2
+        );
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    fs,
+    process::{Command, Stdio},
+};
+
+use crate::template::{
+    aoc_cli,
+    commands::scaffold,
+    config,
+    readme_benchmarks::{self, TableRow},
+    ANSI_BOLD, ANSI_RESET,
+};
+use crate::{all_days, Day, Part};
+
+/// What exists for a day in the repository.
+struct DayStatus {
+    day: Day,
+    has_bin: bool,
+    has_input: bool,
+    has_example: bool,
+    has_puzzle: bool,
+    /// `None` if there is no binary to test.
+    tests_pass: Option<bool>,
+    part_two_unlocked: bool,
+    benchmark: Option<TableRow>,
+}
+
+pub fn handle(json: bool) {
+    let benchmarks = readme_benchmarks::read().unwrap_or_default();
+
+    let statuses: Vec<DayStatus> = all_days()
+        .map(|day| {
+            let has_bin = config::resolve(scaffold::get_module_path(day)).exists();
+            let puzzle = fs::read_to_string(aoc_cli::get_puzzle_path(day)).ok();
+
+            DayStatus {
+                day,
+                has_bin,
+                has_input: is_non_empty(&aoc_cli::get_input_path(day)),
+                has_example: has_example(day),
+                has_puzzle: puzzle.is_some(),
+                tests_pass: has_bin.then(|| run_tests(day)),
+                part_two_unlocked: puzzle.is_some_and(|p| is_part_two_unlocked(&p)),
+                benchmark: benchmarks.iter().find(|row| row.day == day).cloned(),
+            }
+        })
+        .collect();
+
+    if json {
+        println!("{}", to_json(&statuses));
+    } else {
+        print_table(&statuses);
+    }
+}
+
+fn is_non_empty(path: &str) -> bool {
+    fs::read_to_string(path).is_ok_and(|s| !s.trim().is_empty())
+}
+
+/// A day has an example if the shared example file or one for a specific part is non-empty.
+fn has_example(day: Day) -> bool {
+    let shared = scaffold::get_example_path(day);
+    let parts = [Part::One, Part::Two].map(|part| shared.replace(".txt", &format!("-{part}.txt")));
+    is_non_empty(&shared) || parts.iter().any(|path| is_non_empty(path))
+}
+
+/// Runs the tests of the binary and, if the day is a library module, the tests of the module.
+fn run_tests(day: Day) -> bool {
+    let cargo_test = |args: &[&str]| {
+        Command::new("cargo")
+            .args(["test", "--quiet"])
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok_and(|status| status.success())
+    };
+
+    let module = format!("solutions::day{day}::");
+    let has_module = config::resolve(scaffold::get_lib_module_path(day)).exists();
+
+    cargo_test(&["--bin", &day.to_string()]) && (!has_module || cargo_test(&["--lib", &module]))
+}
+
+/// The puzzle description only contains part two once part one is solved.
+fn is_part_two_unlocked(puzzle: &str) -> bool {
+    puzzle.contains("--- Part Two ---")
+}
+
+fn print_table(statuses: &[DayStatus]) {
+    let mark = |value: bool| if value { "✔" } else { "✖" };
+
+    println!(
+        "{ANSI_BOLD}| Day | Bin | Input | Example | Puzzle | Tests | Part 2 | Benchmark |{ANSI_RESET}"
+    );
+
+    for status in statuses {
+        let benchmark = status.benchmark.as_ref().map_or("-".to_string(), |row| {
+            format!(
+                "{} / {}",
+                row.part_1.as_deref().unwrap_or("-"),
+                row.part_2.as_deref().unwrap_or("-")
+            )
+        });
+
+        println!(
+            "| {} | {} | {} | {} | {} | {} | {} | {benchmark} |",
+            status.day,
+            mark(status.has_bin),
+            mark(status.has_input),
+            mark(status.has_example),
+            mark(status.has_puzzle),
+            status.tests_pass.map_or("-", mark),
+            mark(status.part_two_unlocked),
+        );
+    }
+}
+
+fn to_json(statuses: &[DayStatus]) -> String {
+    let string = |s: &Option<String>| match s {
+        Some(s) => format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\"")),
+        None => "null".to_string(),
+    };
+
+    let rows: Vec<String> = statuses
+        .iter()
+        .map(|status| {
+            let tests = status
+                .tests_pass
+                .map_or("null".to_string(), |pass| pass.to_string());
+            let benchmark = status.benchmark.as_ref().map_or("null".to_string(), |row| {
+                format!(
+                    "{{\"part_1\":{},\"part_2\":{}}}",
+                    string(&row.part_1),
+                    string(&row.part_2)
+                )
+            });
+
+            format!(
+                "{{\"day\":{},\"bin\":{},\"input\":{},\"example\":{},\"puzzle\":{},\"tests\":{tests},\"part_two_unlocked\":{},\"benchmark\":{benchmark}}}",
+                status.day.into_inner(),
+                status.has_bin,
+                status.has_input,
+                status.has_example,
+                status.has_puzzle,
+                status.part_two_unlocked,
+            )
+        })
+        .collect();
+
+    format!("[{}]", rows.join(","))
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{is_part_two_unlocked, to_json, DayStatus};
+    use crate::day;
+    use crate::template::readme_benchmarks::TableRow;
+
+    #[test]
+    fn detects_part_two() {
+        assert!(!is_part_two_unlocked("## --- Day 1: Trebuchet?! ---\n..."));
+        assert!(is_part_two_unlocked(
+            "## --- Day 1: Trebuchet?! ---\n...\n## --- Part Two ---\n..."
+        ));
+    }
+
+    #[test]
+    fn formats_json() {
+        let statuses = [
+            DayStatus {
+                day: day!(1),
+                has_bin: true,
+                has_input: true,
+                has_example: true,
+                has_puzzle: true,
+                tests_pass: Some(true),
+                part_two_unlocked: true,
+                benchmark: Some(TableRow {
+                    day: day!(1),
+                    part_1: Some("30.3µs".into()),
+                    part_2: None,
+                    peak_memory: None,
+                }),
+            },
+            DayStatus {
+                day: day!(2),
+                has_bin: false,
+                has_input: false,
+                has_example: false,
+                has_puzzle: false,
+                tests_pass: None,
+                part_two_unlocked: false,
+                benchmark: None,
+            },
+        ];
+
+        assert_eq!(
+            to_json(&statuses),
+            [
+                r#"[{"day":1,"bin":true,"input":true,"example":true,"puzzle":true,"tests":true,"part_two_unlocked":true,"benchmark":{"part_1":"30.3µs","part_2":null}},"#,
+                r#"{"day":2,"bin":false,"input":false,"example":false,"puzzle":false,"tests":null,"part_two_unlocked":false,"benchmark":null}]"#,
+            ]
+            .concat()
+        );
+    }
+}
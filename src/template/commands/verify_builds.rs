@@ -0,0 +1,244 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    process::{self, Command},
+};
+
+use crate::template::{commands::scaffold, config, ANSI_BOLD, ANSI_RESET};
+use crate::{all_days, Day};
+
+/// A build configuration the solutions are run in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Build {
+    Debug,
+    Release,
+    /// Release with `overflow-checks = true`, see the `release-checked` profile in `Cargo.toml`.
+    ReleaseChecked,
+}
+
+impl Build {
+    fn cargo_args(self) -> &'static [&'static str] {
+        match self {
+            Build::Debug => &[],
+            Build::Release => &["--release"],
+            Build::ReleaseChecked => &["--profile", "release-checked"],
+        }
+    }
+}
+
+impl Display for Build {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Build::Debug => write!(f, "debug"),
+            Build::Release => write!(f, "release"),
+            Build::ReleaseChecked => write!(f, "release-checked"),
+        }
+    }
+}
+
+/// What a solution printed in one build.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Outcome {
+    Answers(Vec<String>),
+    /// The binary did not exit successfully, with the panic message if there was one.
+    Failed(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Outcome::Answers(lines) => write!(f, "{}", lines.join(" / ")),
+            Outcome::Failed(message) => write!(f, "failed: {message}"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Verdict {
+    Consistent,
+    /// Every build failed the same way, e.g. because the input is missing.
+    FailedEverywhere,
+    Inconsistent,
+}
+
+pub fn handle(overflow_checks: bool) {
+    let mut builds = vec![Build::Debug, Build::Release];
+    if overflow_checks {
+        builds.push(Build::ReleaseChecked);
+    }
+
+    let mut inconsistent: Vec<Day> = vec![];
+
+    for day in all_days().filter(|&day| config::resolve(scaffold::get_module_path(day)).exists()) {
+        let outcomes: Vec<(Build, Outcome)> = builds
+            .iter()
+            .map(|&build| (build, run(day, build)))
+            .collect();
+
+        match verdict(&outcomes) {
+            Verdict::Consistent => println!("{ANSI_BOLD}Day {day}{ANSI_RESET}: ✔"),
+            Verdict::FailedEverywhere => {
+                println!("{ANSI_BOLD}Day {day}{ANSI_RESET}: fails in every build, skipped");
+            }
+            Verdict::Inconsistent => {
+                println!("{ANSI_BOLD}Day {day}{ANSI_RESET}: ✖ builds disagree");
+                for (build, outcome) in &outcomes {
+                    println!("  {build}: {outcome}");
+                }
+                inconsistent.push(day);
+            }
+        }
+    }
+
+    if !inconsistent.is_empty() {
+        let days: Vec<String> = inconsistent.iter().map(ToString::to_string).collect();
+        eprintln!(
+            "Answers differ between builds on day(s) {}.",
+            days.join(", ")
+        );
+        process::exit(1);
+    }
+}
+
+fn run(day: Day, build: Build) -> Outcome {
+    let output = Command::new("cargo")
+        .args(["run", "--quiet", "--bin", &day.to_string()])
+        .args(build.cargo_args())
+        .output();
+
+    match output {
+        Ok(output) if output.status.success() => {
+            Outcome::Answers(normalize_output(&String::from_utf8_lossy(&output.stdout)))
+        }
+        Ok(output) => Outcome::Failed(panic_message(&String::from_utf8_lossy(&output.stderr))),
+        Err(e) => Outcome::Failed(e.to_string()),
+    }
+}
+
+fn verdict(outcomes: &[(Build, Outcome)]) -> Verdict {
+    let Some((_, first)) = outcomes.first() else {
+        return Verdict::Consistent;
+    };
+
+    // builds that fail with different messages disagree as well.
+    if !outcomes.iter().all(|(_, o)| o == first) {
+        Verdict::Inconsistent
+    } else if matches!(first, Outcome::Failed(_)) {
+        Verdict::FailedEverywhere
+    } else {
+        Verdict::Consistent
+    }
+}
+
+/// The runner overwrites each result line with `\r`, highlights answers with ANSI codes and
+/// appends how long the part took, only the visible answers are compared.
+fn normalize_output(stdout: &str) -> Vec<String> {
+    stdout
+        .lines()
+        .map(|line| strip_ansi(line.rsplit('\r').next().unwrap_or(line)))
+        .map(|line| strip_duration(line.trim_end()).to_string())
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+fn strip_duration(line: &str) -> &str {
+    match line.rfind(" (") {
+        Some(i) if line.starts_with("Part ") && line.ends_with(')') => &line[..i],
+        _ => line,
+    }
+}
+
+fn strip_ansi(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // skip the escape sequence up to and including its final letter.
+            chars.by_ref().find(char::is_ascii_alphabetic);
+        } else {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// The line after `panicked at <location>:` holds the message.
+fn panic_message(stderr: &str) -> String {
+    let mut lines = stderr.lines();
+    lines
+        .find(|line| line.contains("panicked at"))
+        .and_then(|_| lines.next())
+        .or_else(|| stderr.lines().find(|line| !line.trim().is_empty()))
+        .unwrap_or("exited unsuccessfully")
+        .trim()
+        .to_string()
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{normalize_output, panic_message, verdict, Build, Outcome, Verdict};
+
+    fn answers(lines: &[&str]) -> Outcome {
+        Outcome::Answers(lines.iter().map(ToString::to_string).collect())
+    }
+
+    #[test]
+    fn normalizes_output() {
+        assert_eq!(
+            normalize_output(
+                "Part 1: \x1b[1m42\x1b[0m\rPart 1: \x1b[1m42\x1b[0m (1.2µs)\nPart 2: ✖\rPart 2: ✖             \n"
+            ),
+            ["Part 1: 42", "Part 2: ✖"]
+        );
+        assert_eq!(
+            normalize_output("Part 1: ▼ (3.0ms)\n#..#\n#..#\n"),
+            ["Part 1: ▼", "#..#", "#..#"]
+        );
+    }
+
+    #[test]
+    fn reads_panic_message() {
+        let stderr = "\nthread 'main' panicked at src/bin/04.rs:12:5:\nattempt to add with overflow\nnote: run with `RUST_BACKTRACE=1`";
+        assert_eq!(panic_message(stderr), "attempt to add with overflow");
+        assert_eq!(
+            panic_message("error: no bin target"),
+            "error: no bin target"
+        );
+    }
+
+    #[test]
+    fn compares_builds() {
+        let ok = answers(&["Part 1: 42", "Part 2: 7"]);
+        let wrapped = answers(&["Part 1: 42", "Part 2: 4294967295"]);
+        let overflow = Outcome::Failed("attempt to add with overflow".into());
+        let out_of_bounds = Outcome::Failed("index out of bounds".into());
+
+        assert_eq!(
+            verdict(&[(Build::Debug, ok.clone()), (Build::Release, ok.clone())]),
+            Verdict::Consistent
+        );
+        assert_eq!(
+            verdict(&[
+                (Build::Debug, overflow.clone()),
+                (Build::Release, wrapped.clone())
+            ]),
+            Verdict::Inconsistent
+        );
+        assert_eq!(
+            verdict(&[(Build::Debug, ok), (Build::Release, wrapped)]),
+            Verdict::Inconsistent
+        );
+        assert_eq!(
+            verdict(&[
+                (Build::Debug, overflow.clone()),
+                (Build::Release, overflow.clone())
+            ]),
+            Verdict::FailedEverywhere
+        );
+        assert_eq!(
+            verdict(&[(Build::Debug, overflow), (Build::Release, out_of_bounds)]),
+            Verdict::Inconsistent
+        );
+    }
+}
@@ -0,0 +1,650 @@
+/// Reads the project configuration in `aoc.toml`.
+///
+/// Settings are top-level keys or keys of a `[section]`, with booleans, integers, floats or
+/// strings as values. Keys are addressed as `section.key`.
+use std::{
+    collections::BTreeMap,
+    env,
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+    time::Duration,
+};
+
+use crate::template::benchmark_chart;
+use crate::template::readme_benchmarks::{self, parse_duration};
+
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+/// The type of a setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Bool,
+    Integer,
+    String,
+    Duration,
+}
+
+impl Kind {
+    fn expected(self) -> &'static str {
+        match self {
+            Kind::Bool => "a boolean",
+            Kind::Integer => "an integer",
+            Kind::String => "a string",
+            Kind::Duration => "a duration like `2` (seconds) or `\"500ms\"`",
+        }
+    }
+
+    fn matches(self, value: &Value) -> bool {
+        matches!(
+            (self, value),
+            (Kind::Bool, Value::Bool(_))
+                | (Kind::Integer, Value::Integer(_))
+                | (Kind::String | Kind::Duration, Value::String(_))
+                | (Kind::Duration, Value::Integer(_) | Value::Float(_))
+        )
+    }
+}
+
+/// Every setting of `aoc.toml`. Each one can be overridden by an environment variable
+/// named after its key, e.g. `AOC_YEAR` for `year` or `AOC_PATHS_DATA` for `paths.data`.
+const KEYS: &[(&str, Kind)] = &[
+    ("year", Kind::Integer),
+    ("session_file", Kind::String),
+    ("paths.data", Kind::String),
+    ("paths.inputs", Kind::String),
+    ("paths.examples", Kind::String),
+    ("paths.puzzles", Kind::String),
+    ("paths.answers", Kind::String),
+    ("paths.generated", Kind::String),
+    ("paths.benchmarks", Kind::String),
+    ("readme.path", Kind::String),
+    ("readme.chart", Kind::String),
+    ("readme.benchmarks_marker", Kind::String),
+    ("readme.stars_marker", Kind::String),
+    ("solve.release", Kind::Bool),
+    ("solve.time", Kind::Bool),
+    ("all.release", Kind::Bool),
+    ("all.time", Kind::Bool),
+    ("all.jobs", Kind::Integer),
+    ("scaffold.template", Kind::String),
+    ("bench.time", Kind::Duration),
+    ("bench.min_samples", Kind::Integer),
+    ("bench.max_samples", Kind::Integer),
+    ("bench.warmup", Kind::Integer),
+];
+
+/// The environment variable that overrides a key.
+fn env_var(key: &str) -> String {
+    format!("AOC_{}", key.replace('.', "_").to_uppercase())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+}
+
+impl Value {
+    fn from_toml(key: &str, value: toml::Value) -> Result<Self, ConfigError> {
+        match value {
+            toml::Value::Boolean(b) => Ok(Value::Bool(b)),
+            toml::Value::Integer(i) => Ok(Value::Integer(i)),
+            toml::Value::Float(x) => Ok(Value::Float(x)),
+            toml::Value::String(s) => Ok(Value::String(s)),
+            _ => Err(ConfigError::InvalidValue {
+                key: key.to_string(),
+                expected: "a boolean, a number or a string",
+            }),
+        }
+    }
+
+    /// Parses the value of an environment variable, which is a string if it is nothing else.
+    fn from_env(raw: String) -> Self {
+        if let Ok(b) = raw.parse() {
+            Value::Bool(b)
+        } else if let Ok(i) = raw.parse() {
+            Value::Integer(i)
+        } else if let Ok(x) = raw.parse() {
+            Value::Float(x)
+        } else {
+            Value::String(raw)
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Value::Bool(b) => write!(f, "{b}"),
+            Value::Integer(i) => write!(f, "{i}"),
+            Value::Float(x) => write!(f, "{x}"),
+            Value::String(s) => write!(f, "{s:?}"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    IO(io::Error),
+    Syntax { line: usize, message: String },
+    UnknownKey(String),
+    InvalidValue { key: String, expected: &'static str },
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            ConfigError::IO(e) => write!(f, "could not read {CONFIG_PATH}: {e}"),
+            ConfigError::Syntax { line, message } => {
+                write!(f, "{CONFIG_PATH}:{line}: {message}")
+            }
+            ConfigError::UnknownKey(key) => write!(f, "{CONFIG_PATH}: unknown key `{key}`"),
+            ConfigError::InvalidValue { key, expected } => {
+                write!(f, "{CONFIG_PATH}: `{key}` must be {expected}")
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    values: BTreeMap<String, Value>,
+}
+
+impl Config {
+    /// Loads `aoc.toml` from the root of the crate and applies the overrides from the environment.
+    /// A missing file is an empty config.
+    pub fn load() -> Result<Self, ConfigError> {
+        let path = root().join(CONFIG_PATH);
+        let contents = match fs::read_to_string(path) {
+            Ok(s) => s,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(ConfigError::IO(e)),
+        };
+
+        Config::resolve(&contents, |var| env::var(var).ok())
+    }
+
+    /// Parses a config, checks that every key is known and has the right type, and applies
+    /// the overrides that `env` returns for the environment variables of the keys.
+    fn resolve(s: &str, env: impl Fn(&str) -> Option<String>) -> Result<Self, ConfigError> {
+        let mut config = Config::parse(s)?;
+
+        for (key, value) in &config.values {
+            let Some((_, kind)) = KEYS.iter().find(|(k, _)| k == key) else {
+                return Err(ConfigError::UnknownKey(key.clone()));
+            };
+
+            if !kind.matches(value) {
+                return Err(ConfigError::InvalidValue {
+                    key: key.clone(),
+                    expected: kind.expected(),
+                });
+            }
+        }
+
+        for &(key, kind) in KEYS {
+            let var = env_var(key);
+            let Some(raw) = env(&var) else {
+                continue;
+            };
+
+            let value = match kind {
+                Kind::String => Value::String(raw),
+                _ => Value::from_env(raw),
+            };
+
+            if !kind.matches(&value) {
+                return Err(ConfigError::InvalidValue {
+                    key: var,
+                    expected: kind.expected(),
+                });
+            }
+
+            config.values.insert(key.to_string(), value);
+        }
+
+        Ok(config)
+    }
+
+    pub fn parse(s: &str) -> Result<Self, ConfigError> {
+        let table: toml::Table = s.parse().map_err(|e: toml::de::Error| {
+            let offset = e.span().map_or(0, |span| span.start);
+            ConfigError::Syntax {
+                line: s[..offset.min(s.len())].matches('\n').count() + 1,
+                message: e.message().to_string(),
+            }
+        })?;
+
+        let mut values = BTreeMap::new();
+
+        for (key, value) in table {
+            match value {
+                toml::Value::Table(section) => {
+                    for (name, value) in section {
+                        let key = format!("{key}.{name}");
+                        values.insert(key.clone(), Value::from_toml(&key, value)?);
+                    }
+                }
+                value => {
+                    values.insert(key.clone(), Value::from_toml(&key, value)?);
+                }
+            }
+        }
+
+        Ok(Config { values })
+    }
+
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+
+    fn get_string(&self, key: &str) -> Option<&str> {
+        match self.get(key)? {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    fn get_bool(&self, key: &str) -> Option<bool> {
+        match self.get(key)? {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Reads a non-negative integer.
+    pub fn get_u64(&self, key: &str) -> Result<Option<u64>, ConfigError> {
+        match self.get(key) {
+            None => Ok(None),
+            Some(Value::Integer(i)) => {
+                u64::try_from(*i)
+                    .map(Some)
+                    .map_err(|_| ConfigError::InvalidValue {
+                        key: key.to_string(),
+                        expected: "a non-negative integer",
+                    })
+            }
+            Some(_) => Err(ConfigError::InvalidValue {
+                key: key.to_string(),
+                expected: "a non-negative integer",
+            }),
+        }
+    }
+
+    /// Reads a duration, either as seconds or as a string like `"500ms"`.
+    pub fn get_duration(&self, key: &str) -> Result<Option<Duration>, ConfigError> {
+        let invalid = || ConfigError::InvalidValue {
+            key: key.to_string(),
+            expected: "a duration like `2` (seconds) or `\"500ms\"`",
+        };
+
+        #[allow(clippy::cast_precision_loss)]
+        let seconds = match self.get(key) {
+            None => return Ok(None),
+            Some(Value::Integer(i)) => *i as f64,
+            Some(Value::Float(x)) => *x,
+            Some(Value::String(s)) => parse_duration(s).ok_or_else(invalid)? / 1_000_000_000_f64,
+            Some(Value::Bool(_)) => return Err(invalid()),
+        };
+
+        Duration::try_from_secs_f64(seconds)
+            .map(Some)
+            .map_err(|_| invalid())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Where the files of the template live. Every folder is relative to `data`,
+/// which is relative to the root of the crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Paths {
+    pub data: String,
+    pub inputs: String,
+    pub examples: String,
+    pub puzzles: String,
+    pub answers: String,
+    pub generated: String,
+    pub benchmarks: String,
+}
+
+impl Paths {
+    /// The directory of a folder like `inputs` or `examples` in the root of the crate.
+    /// Unknown folders are taken as-is.
+    #[must_use]
+    pub fn dir(&self, folder: &str) -> PathBuf {
+        let name = match folder {
+            "inputs" => &self.inputs,
+            "examples" => &self.examples,
+            "puzzles" => &self.puzzles,
+            "answers" => &self.answers,
+            "generated" => &self.generated,
+            "benchmarks" => &self.benchmarks,
+            folder => folder,
+        };
+
+        resolve(&self.data).join(name)
+    }
+
+    /// The path of a file in a folder, as string.
+    #[must_use]
+    pub fn file(&self, folder: &str, name: &str) -> String {
+        self.dir(folder).join(name).to_string_lossy().into_owned()
+    }
+}
+
+/// The readme and the generated content in it. Paths are relative to the root of the crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Readme {
+    pub path: String,
+    /// The benchmark chart, which the readme links to as `./{chart}`.
+    pub chart: String,
+    pub benchmarks_marker: String,
+    pub stars_marker: String,
+}
+
+/// Flags that `cargo solve` and `cargo all` use even if they are not passed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultFlags {
+    pub release: bool,
+    pub time: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScaffoldTemplate {
+    /// The template built into `cargo scaffold`.
+    Default,
+    /// A file with the module template, in which `DAY_NUMBER` is replaced by the day.
+    /// The path is resolved against the root of the crate.
+    File(String),
+}
+
+/// The typed settings of `aoc.toml`, with defaults for everything that is not set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Project {
+    /// The year of the puzzles, passed to aoc-cli.
+    pub year: Option<u16>,
+    /// The file aoc-cli reads the session cookie from, `~/.adventofcode.session` if not set.
+    pub session_file: Option<PathBuf>,
+    pub paths: Paths,
+    pub readme: Readme,
+    pub solve: DefaultFlags,
+    pub all: DefaultFlags,
+    /// How many solutions `cargo all` runs at the same time.
+    pub jobs: usize,
+    pub scaffold_template: ScaffoldTemplate,
+    /// The raw settings, e.g. for the `[bench]` section.
+    pub config: Config,
+}
+
+impl Project {
+    pub fn from_config(config: Config) -> Result<Self, ConfigError> {
+        let string =
+            |key: &str, default: &str| config.get_string(key).unwrap_or(default).to_string();
+        let invalid = |key: &str, expected| ConfigError::InvalidValue {
+            key: key.to_string(),
+            expected,
+        };
+
+        let year = match config.get_u64("year")? {
+            Some(year) => Some(
+                u16::try_from(year)
+                    .ok()
+                    .filter(|year| *year >= 2015)
+                    .ok_or_else(|| invalid("year", "a year from 2015 on"))?,
+            ),
+            None => None,
+        };
+
+        let readme = Readme {
+            path: string("readme.path", "README.md"),
+            chart: string("readme.chart", benchmark_chart::CHART_PATH),
+            benchmarks_marker: string("readme.benchmarks_marker", readme_benchmarks::MARKER),
+            stars_marker: string("readme.stars_marker", readme_benchmarks::STARS_MARKER),
+        };
+
+        for key in ["readme.benchmarks_marker", "readme.stars_marker"] {
+            if config
+                .get_string(key)
+                .is_some_and(|marker| marker.trim().is_empty())
+            {
+                return Err(invalid(key, "a non-empty string"));
+            }
+        }
+
+        if readme.benchmarks_marker == readme.stars_marker {
+            return Err(invalid(
+                "readme.stars_marker",
+                "different from `readme.benchmarks_marker`",
+            ));
+        }
+
+        let jobs = match config.get_u64("all.jobs")? {
+            Some(0) => return Err(invalid("all.jobs", "a positive integer")),
+            Some(jobs) => {
+                usize::try_from(jobs).map_err(|_| invalid("all.jobs", "a positive integer"))?
+            }
+            None => 1,
+        };
+
+        let scaffold_template = match config.get_string("scaffold.template") {
+            None | Some("default") => ScaffoldTemplate::Default,
+            Some(path) if resolve(path).is_file() => {
+                ScaffoldTemplate::File(resolve(path).to_string_lossy().into_owned())
+            }
+            Some(_) => {
+                return Err(invalid(
+                    "scaffold.template",
+                    "`default` or the path of an existing file",
+                ))
+            }
+        };
+
+        Ok(Project {
+            year,
+            session_file: config.get_string("session_file").map(expand_home),
+            paths: Paths {
+                data: string("paths.data", "data"),
+                inputs: string("paths.inputs", "inputs"),
+                examples: string("paths.examples", "examples"),
+                puzzles: string("paths.puzzles", "puzzles"),
+                answers: string("paths.answers", "answers"),
+                generated: string("paths.generated", "generated"),
+                benchmarks: string("paths.benchmarks", "benchmarks"),
+            },
+            readme,
+            solve: DefaultFlags {
+                release: config.get_bool("solve.release").unwrap_or_default(),
+                time: config.get_bool("solve.time").unwrap_or_default(),
+            },
+            all: DefaultFlags {
+                release: config.get_bool("all.release").unwrap_or_default(),
+                time: config.get_bool("all.time").unwrap_or_default(),
+            },
+            jobs,
+            scaffold_template,
+            config,
+        })
+    }
+}
+
+/// The root of the crate, which `aoc.toml` and the paths in it are relative to.
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+/// Resolves a path against the root of the crate, so it does not depend on the working directory.
+#[must_use]
+pub fn resolve(path: impl AsRef<Path>) -> PathBuf {
+    root().join(path)
+}
+
+/// Replaces a leading `~/` with the home directory.
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), env::var_os("HOME")) {
+        (Some(rest), Some(home)) => Path::new(&home).join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// The project settings, loaded once.
+pub fn load_project() -> Result<&'static Project, &'static ConfigError> {
+    static PROJECT: OnceLock<Result<Project, ConfigError>> = OnceLock::new();
+
+    PROJECT
+        .get_or_init(|| Config::load().and_then(Project::from_config))
+        .as_ref()
+}
+
+/// The project settings. If `aoc.toml` is invalid, warns once and uses the defaults,
+/// so solutions and tests still run. The commands of `cargo` check it up front instead.
+pub fn project() -> &'static Project {
+    static DEFAULTS: OnceLock<Project> = OnceLock::new();
+
+    load_project().unwrap_or_else(|e| {
+        DEFAULTS.get_or_init(|| {
+            eprintln!("Invalid configuration, using the defaults: {e}");
+            Project::from_config(Config::default()).expect("the defaults are valid")
+        })
+    })
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{root, Config, ConfigError, Project, ScaffoldTemplate, Value};
+    use std::{path::PathBuf, time::Duration};
+
+    #[test]
+    fn parses_config() {
+        let config = Config::parse(
+            "# benchmarks\nyear = 2023\n\n[bench]\ntime = \"500ms\" # per part\nmin_samples = 1_000\nrelease = true\nname = \"a # b\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.get("year"), Some(&Value::Integer(2023)));
+        assert_eq!(config.get("bench.release"), Some(&Value::Bool(true)));
+        assert_eq!(
+            config.get("bench.name"),
+            Some(&Value::String("a # b".into()))
+        );
+        assert_eq!(config.get_u64("bench.min_samples").unwrap(), Some(1000));
+        assert_eq!(
+            config.get_duration("bench.time").unwrap(),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(config.get_u64("bench.max_samples").unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_invalid_config() {
+        let syntax_line = |s: &str| match Config::parse(s) {
+            Err(ConfigError::Syntax { line, .. }) => line,
+            other => panic!("expected a syntax error, got {other:?}"),
+        };
+
+        assert_eq!(syntax_line("[bench"), 1);
+        assert_eq!(syntax_line("year = 2023\nyear"), 2);
+        assert_eq!(syntax_line("year = twenty"), 1);
+        assert_eq!(syntax_line("year = 2023\nyear = 2024"), 2);
+
+        assert!(matches!(
+            Config::parse("[bench]\ntime = [1, 2]"),
+            Err(ConfigError::InvalidValue { key, .. }) if key == "bench.time"
+        ));
+
+        let config = Config::parse("[bench]\nmin_samples = -1\ntime = true").unwrap();
+        assert!(config.get_u64("bench.min_samples").is_err());
+        assert!(config.get_duration("bench.time").is_err());
+    }
+
+    #[test]
+    fn validates_keys_and_applies_env_overrides() {
+        let env = |var: &str| match var {
+            "AOC_YEAR" => Some("2022".to_string()),
+            "AOC_PATHS_DATA" => Some("puzzles".to_string()),
+            _ => None,
+        };
+
+        let config = Config::resolve("year = 2023\n[all]\njobs = 4", env).unwrap();
+        assert_eq!(config.get("year"), Some(&Value::Integer(2022)));
+        assert_eq!(
+            config.get("paths.data"),
+            Some(&Value::String("puzzles".into()))
+        );
+
+        assert!(matches!(
+            Config::resolve("[paths]\ninput = \"in\"", |_| None),
+            Err(ConfigError::UnknownKey(key)) if key == "paths.input"
+        ));
+        assert!(matches!(
+            Config::resolve("[solve]\nrelease = \"yes\"", |_| None),
+            Err(ConfigError::InvalidValue { key, .. }) if key == "solve.release"
+        ));
+        assert!(matches!(
+            Config::resolve("", |var| (var == "AOC_ALL_TIME").then(|| "sometimes".into())),
+            Err(ConfigError::InvalidValue { key, .. }) if key == "AOC_ALL_TIME"
+        ));
+    }
+
+    #[test]
+    fn reads_project() {
+        let project = Project::from_config(Config::default()).unwrap();
+        assert_eq!(project.year, None);
+        assert_eq!(project.jobs, 1);
+        assert_eq!(project.readme.path, "README.md");
+        assert_eq!(project.readme.chart, ".assets/benchmarks.svg");
+        assert_eq!(project.scaffold_template, ScaffoldTemplate::Default);
+        // folders are found relative to the crate, not the current directory.
+        assert_eq!(
+            project.paths.dir("inputs"),
+            root().join("data").join("inputs")
+        );
+
+        let config = Config::parse(
+            "year = 2019\n[paths]\ndata = \"aoc\"\ninputs = \"in\"\n[all]\ntime = true\njobs = 2",
+        )
+        .unwrap();
+        let project = Project::from_config(config).unwrap();
+        assert_eq!(project.year, Some(2019));
+        assert_eq!(
+            project.paths.file("inputs", "01.txt"),
+            root()
+                .join("aoc")
+                .join("in")
+                .join("01.txt")
+                .to_string_lossy()
+        );
+        assert!(project.all.time && !project.all.release);
+        assert_eq!(project.jobs, 2);
+
+        // the template is found relative to the crate, not the current directory.
+        let config = Config::parse("[scaffold]\ntemplate = \"Cargo.toml\"").unwrap();
+        let ScaffoldTemplate::File(path) = Project::from_config(config).unwrap().scaffold_template
+        else {
+            panic!("expected a template file");
+        };
+        assert_eq!(PathBuf::from(path), root().join("Cargo.toml"));
+
+        for invalid in [
+            "year = 1999",
+            "[all]\njobs = 0",
+            "[readme]\nstars_marker = \"\"",
+            "[readme]\nbenchmarks_marker = \"x\"\nstars_marker = \"x\"",
+            "[scaffold]\ntemplate = \"does/not/exist.rs\"",
+        ] {
+            let config = Config::parse(invalid).unwrap();
+            assert!(Project::from_config(config).is_err(), "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,52 @@
+/// Measures the CPU time of the whole process, i.e. the time all of its threads spent
+/// running in user and kernel mode. Compared to the wall time of a parallel solution,
+/// this shows how many cores were busy.
+use std::time::Duration;
+
+/// Returns the CPU time the process used so far, if the platform supports measuring it.
+#[cfg(unix)]
+#[must_use]
+pub fn process_cpu_time() -> Option<Duration> {
+    let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+
+    // SAFETY: `getrusage` fully initializes `usage` if it returns 0.
+    let usage = unsafe {
+        if libc::getrusage(libc::RUSAGE_SELF, usage.as_mut_ptr()) != 0 {
+            return None;
+        }
+        usage.assume_init()
+    };
+
+    let to_duration = |t: libc::timeval| -> Option<Duration> {
+        let secs = u64::try_from(t.tv_sec).ok()?;
+        let micros = u32::try_from(t.tv_usec).ok()?;
+        Some(Duration::new(secs, micros * 1000))
+    };
+
+    Some(to_duration(usage.ru_utime)? + to_duration(usage.ru_stime)?)
+}
+
+/// Returns the CPU time the process used so far, if the platform supports measuring it.
+#[cfg(not(unix))]
+#[must_use]
+pub fn process_cpu_time() -> Option<Duration> {
+    None
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::process_cpu_time;
+
+    #[test]
+    #[cfg(unix)]
+    fn increases_with_work() {
+        let before = process_cpu_time().unwrap();
+        let mut x = 0_u64;
+        for i in 0..50_000_000_u64 {
+            x = std::hint::black_box(x.wrapping_mul(31).wrapping_add(i));
+        }
+        assert!(process_cpu_time().unwrap() > before);
+    }
+}
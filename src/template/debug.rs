@@ -0,0 +1,63 @@
+/// Debug output of solutions, see [`crate::aoc_debug`].
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+/// `false` in builds with the `no_debug` feature, which lets the compiler remove every `aoc_debug!`.
+pub const ENABLED: bool = cfg!(not(feature = "no_debug"));
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+static MUTED: AtomicBool = AtomicBool::new(false);
+
+/// Sets how many `-v` flags were passed.
+pub fn set_verbosity(verbosity: u8) {
+    VERBOSITY.store(verbosity, Ordering::Relaxed);
+}
+
+/// Whether a message of the given level is printed right now.
+#[must_use]
+pub fn enabled(level: u8) -> bool {
+    ENABLED && level <= VERBOSITY.load(Ordering::Relaxed) && !MUTED.load(Ordering::Relaxed)
+}
+
+/// Runs `f` without printing debug output, e.g. while benchmarking.
+pub fn muted<T>(f: impl FnOnce() -> T) -> T {
+    let was_muted = MUTED.swap(true, Ordering::Relaxed);
+    let result = f();
+    MUTED.store(was_muted, Ordering::Relaxed);
+    result
+}
+
+/// Prints to stderr if the solution runs with at least `-v`, or the given level of `-v`s.
+/// Output is only shown on the first run of a part, not while benchmarking.
+///
+/// ```
+/// advent_of_code::aoc_debug!("found cycle @{i}");
+/// advent_of_code::aoc_debug!(2; "cycles: {cycles:?}");
+/// ```
+#[macro_export]
+macro_rules! aoc_debug {
+    ($level:literal; $($arg:tt)*) => {
+        if $crate::template::debug::enabled($level) {
+            eprintln!($($arg)*);
+        }
+    };
+    ($($arg:tt)*) => {
+        $crate::aoc_debug!(1; $($arg)*)
+    };
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{enabled, muted, set_verbosity, ENABLED};
+
+    #[test]
+    fn respects_verbosity() {
+        set_verbosity(1);
+        assert_eq!(enabled(1), ENABLED);
+        assert!(!enabled(2));
+        assert!(!muted(|| enabled(1)));
+        assert_eq!(enabled(1), ENABLED);
+
+        set_verbosity(0);
+        assert!(!enabled(1));
+    }
+}
@@ -0,0 +1,346 @@
+/// Input generators for the individual days.
+/// Sizes at `scale == 1` roughly match the real puzzle inputs.
+use super::{InputGenerator, Rng};
+use crate::Day;
+
+pub fn generator(day: Day) -> Option<&'static dyn InputGenerator> {
+    match day.into_inner() {
+        1 => Some(&Day01),
+        2 => Some(&Day02),
+        3 => Some(&Day03),
+        4 => Some(&Day04),
+        5 => Some(&Day05),
+        6 => Some(&Day06),
+        8 => Some(&Day08),
+        9 => Some(&Day09),
+        _ => None,
+    }
+}
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+struct Day01;
+
+impl InputGenerator for Day01 {
+    fn generate(&self, scale: usize, rng: &mut Rng) -> String {
+        let mut lines = Vec::with_capacity(1000 * scale);
+
+        for _ in 0..1000 * scale {
+            let mut line = String::new();
+            let mut has_digit = false;
+
+            for _ in 0..rng.range(3..12) {
+                match rng.range(0..10) {
+                    0..=5 => line.push(char::from(b'a' + rng.range(0..26) as u8)),
+                    6..=7 => {
+                        line.push(char::from(b'1' + rng.range(0..9) as u8));
+                        has_digit = true;
+                    }
+                    _ => line.push_str(rng.choose::<&str>(&DIGIT_WORDS)),
+                }
+            }
+
+            // part one needs at least one digit per line.
+            if !has_digit {
+                let pos = rng.range(0..line.len() as u64 + 1) as usize;
+                line.insert(pos, char::from(b'1' + rng.range(0..9) as u8));
+            }
+
+            lines.push(line);
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+struct Day02;
+
+impl InputGenerator for Day02 {
+    fn generate(&self, scale: usize, rng: &mut Rng) -> String {
+        let mut lines = Vec::with_capacity(100 * scale);
+
+        for id in 1..=100 * scale {
+            let picks: Vec<String> = (0..rng.range(1..7))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    rng.shuffle(&mut colors);
+                    let n_colors = rng.range(1..4) as usize;
+                    colors[..n_colors]
+                        .iter()
+                        .map(|color| format!("{} {color}", rng.range(1..21)))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect();
+
+            lines.push(format!("Game {id}: {}", picks.join("; ")));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+struct Day03;
+
+impl InputGenerator for Day03 {
+    fn generate(&self, scale: usize, rng: &mut Rng) -> String {
+        const COLS: usize = 140;
+        const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+        let mut lines = Vec::with_capacity(COLS * scale);
+
+        for _ in 0..COLS * scale {
+            let mut row = vec!['.'; COLS];
+            let mut col = 0;
+
+            while col < COLS {
+                if rng.chance(0.12) {
+                    let len = rng.range(1..4) as usize;
+                    if col + len <= COLS {
+                        row[col] = char::from(b'1' + rng.range(0..9) as u8);
+                        for cell in &mut row[col + 1..col + len] {
+                            *cell = char::from(b'0' + rng.range(0..10) as u8);
+                        }
+                        // keep the next cell free so numbers do not merge.
+                        col += len + 1;
+                        continue;
+                    }
+                } else if rng.chance(0.05) {
+                    row[col] = *rng.choose(&SYMBOLS);
+                }
+                col += 1;
+            }
+
+            lines.push(row.into_iter().collect::<String>());
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+struct Day04;
+
+impl InputGenerator for Day04 {
+    fn generate(&self, scale: usize, rng: &mut Rng) -> String {
+        let mut lines = Vec::with_capacity(200 * scale);
+
+        for id in 1..=200 * scale {
+            let mut pool: Vec<u64> = (1..100).collect();
+            rng.shuffle(&mut pool);
+            let (winning, rest) = pool.split_at(10);
+
+            // most cards win nothing, which keeps the number of copies in part two bounded.
+            let matches = if rng.chance(0.7) {
+                0
+            } else {
+                rng.range(1..5) as usize
+            };
+            let mut scratch: Vec<u64> = winning[..matches]
+                .iter()
+                .chain(&rest[..25 - matches])
+                .copied()
+                .collect();
+            rng.shuffle(&mut scratch);
+
+            let format = |numbers: &[u64]| {
+                numbers
+                    .iter()
+                    .map(|n| format!("{n:>2}"))
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+
+            lines.push(format!(
+                "Card {id:>3}: {} | {}",
+                format(winning),
+                format(&scratch)
+            ));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
+
+struct Day05;
+
+impl Day05 {
+    /// Creates an almanac with `n_seeds` seed ranges and maps of `n_segments` ranges over `0..upper`.
+    fn almanac(rng: &mut Rng, n_seeds: usize, n_segments: usize, upper: u64) -> String {
+        const MAPS: [&str; 7] = [
+            "seed-to-soil",
+            "soil-to-fertilizer",
+            "fertilizer-to-water",
+            "water-to-light",
+            "light-to-temperature",
+            "temperature-to-humidity",
+            "humidity-to-location",
+        ];
+
+        let seeds: Vec<String> = (0..n_seeds)
+            .map(|_| {
+                let start = rng.range(0..upper / 2);
+                format!("{start} {}", rng.range(1..upper / 8))
+            })
+            .collect();
+
+        let mut sections = vec![format!("seeds: {}", seeds.join(" "))];
+
+        for name in MAPS {
+            // split the source space into segments and lay them out in shuffled order.
+            let mut cuts: Vec<u64> = (0..n_segments - 1).map(|_| rng.range(1..upper)).collect();
+            cuts.push(0);
+            cuts.push(upper);
+            cuts.sort_unstable();
+            cuts.dedup();
+
+            let segments: Vec<(u64, u64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+            let mut order: Vec<usize> = (0..segments.len()).collect();
+            rng.shuffle(&mut order);
+
+            let mut destination = 0;
+            let mut destinations = vec![0; segments.len()];
+            for i in order {
+                destinations[i] = destination;
+                destination += segments[i].1;
+            }
+
+            let mut lines = vec![format!("{name} map:")];
+            for (i, (source, length)) in segments.into_iter().enumerate() {
+                // leave some segments unmapped, they keep their value.
+                if rng.chance(0.8) {
+                    lines.push(format!("{} {source} {length}", destinations[i]));
+                }
+            }
+            sections.push(lines.join("\n"));
+        }
+
+        sections.join("\n\n") + "\n"
+    }
+}
+
+impl InputGenerator for Day05 {
+    fn generate(&self, scale: usize, rng: &mut Rng) -> String {
+        Self::almanac(rng, 10 * scale, 30 * scale, 1 << 32)
+    }
+
+    fn generate_small(&self, rng: &mut Rng) -> String {
+        Self::almanac(rng, 4, 6, 128)
+    }
+}
+
+struct Day06;
+
+impl InputGenerator for Day06 {
+    /// Always creates four races, `scale` is ignored, see [`InputGenerator::scales`].
+    fn generate(&self, _scale: usize, rng: &mut Rng) -> String {
+        let races: Vec<(u64, u64)> = (0..4)
+            .map(|_| (rng.range(40..100), rng.range(100..400)))
+            .collect();
+
+        let times: String = races.iter().map(|(t, _)| format!("{t:>5}")).collect();
+        let records: String = races.iter().map(|(_, r)| format!("{r:>5}")).collect();
+
+        format!("Time:    {times}\nDistance:{records}\n")
+    }
+
+    /// Part two concatenates all numbers into one, which overflows with more races.
+    fn scales(&self) -> bool {
+        false
+    }
+}
+
+struct Day08;
+
+impl InputGenerator for Day08 {
+    /// Every ghost walks a cycle whose length is a multiple of the instruction length.
+    /// The network has the same size at every `scale`, see [`InputGenerator::scales`].
+    fn generate(&self, _scale: usize, rng: &mut Rng) -> String {
+        const PRIMES: [usize; 6] = [43, 47, 53, 59, 61, 67];
+
+        let letter = |i: usize| char::from(b'A' + (i % 26) as u8);
+        let name = |i: usize, last: char| format!("{}{}{last}", letter(i / 26), letter(i));
+
+        let n_instructions = 5;
+        let instructions: Vec<char> = (0..n_instructions)
+            .map(|_| if rng.chance(0.5) { 'L' } else { 'R' })
+            .collect();
+
+        let mut n_inner = 0;
+        let mut inner_name = || {
+            let i = n_inner;
+            n_inner += 1;
+            // skip A and Z as last letter, those mark start and end nodes.
+            name(i / 24, char::from(b'B' + (i % 24) as u8))
+        };
+
+        let mut nodes = vec![];
+
+        for (ghost, prime) in PRIMES.iter().enumerate() {
+            let length = n_instructions * prime;
+            let start = name(ghost, 'A');
+            let end = if ghost == 0 {
+                "ZZZ".to_string()
+            } else {
+                name(ghost, 'Z')
+            };
+
+            // path[0] is the start node, path[length] the end node, which loops back to path[1].
+            let mut path = vec![start];
+            path.extend((1..length).map(|_| inner_name()));
+            path.push(end);
+
+            for (i, node) in path.iter().enumerate() {
+                let next = if i == length { &path[1] } else { &path[i + 1] };
+                let other = rng.choose(&path[1..]);
+                let (left, right) = match instructions[i % n_instructions] {
+                    'L' => (next, other),
+                    _ => (other, next),
+                };
+                nodes.push(format!("{node} = ({left}, {right})"));
+            }
+        }
+
+        rng.shuffle(&mut nodes);
+
+        format!(
+            "{}\n\n{}\n",
+            instructions.into_iter().collect::<String>(),
+            nodes.join("\n")
+        )
+    }
+
+    /// Node names are limited to three letters, which caps the size of the network.
+    fn scales(&self) -> bool {
+        false
+    }
+}
+
+struct Day09;
+
+impl InputGenerator for Day09 {
+    fn generate(&self, scale: usize, rng: &mut Rng) -> String {
+        let mut lines = Vec::with_capacity(200 * scale);
+
+        for _ in 0..200 * scale {
+            let coefficients: Vec<i64> = (0..rng.range(1..7))
+                .map(|_| rng.range(0..11) as i64 - 5)
+                .collect();
+
+            let values: Vec<String> = (0..21_i64)
+                .map(|x| {
+                    coefficients
+                        .iter()
+                        .rev()
+                        .fold(0, |acc, c| acc * x + c)
+                        .to_string()
+                })
+                .collect();
+
+            lines.push(values.join(" "));
+        }
+
+        lines.join("\n") + "\n"
+    }
+}
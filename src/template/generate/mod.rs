@@ -0,0 +1,140 @@
+/// Module that creates synthetic puzzle inputs of arbitrary size.
+/// Inputs are derived from a seed, so the same `(day, scale, seed)` always yields the same input.
+use std::ops::Range;
+
+use crate::Day;
+
+mod days;
+
+/// Seed that is used when none is passed on the command-line.
+pub const DEFAULT_SEED: u64 = 2023;
+
+/// A small, deterministic pseudo-random number generator (`SplitMix64`).
+///
+/// This is not suitable for anything security related, it only exists so
+/// generated inputs and tests do not need an external RNG crate.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `range`. Panics if the range is empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "cannot pick a value from an empty range");
+        range.start + self.next_u64() % (range.end - range.start)
+    }
+
+    /// Returns `true` with a probability of `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        #[allow(clippy::cast_precision_loss)]
+        let sample = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        sample < p
+    }
+
+    /// Returns a random element of `items`. Panics if `items` is empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.range(0..i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Implemented by days that can create inputs for themselves.
+pub trait InputGenerator {
+    /// Creates an input that is roughly `scale` times the size of a real puzzle input.
+    fn generate(&self, scale: usize, rng: &mut Rng) -> String;
+
+    /// Creates an input that is small enough for brute-force implementations.
+    fn generate_small(&self, rng: &mut Rng) -> String {
+        self.generate(1, rng)
+    }
+
+    /// Whether the size of the input grows with `scale`. Days whose input cannot grow
+    /// are left out of `cargo time --scales`.
+    fn scales(&self) -> bool {
+        true
+    }
+}
+
+/// Returns the input generator for a given day, if one exists.
+#[must_use]
+pub fn generator(day: Day) -> Option<&'static dyn InputGenerator> {
+    days::generator(day)
+}
+
+/// Creates an input for `day` at the given `scale`.
+/// Returns [`None`] if the day has no input generator.
+#[must_use]
+pub fn generate(day: Day, scale: usize, seed: u64) -> Option<String> {
+    let mut rng = Rng::new(seed);
+    generator(day).map(|g| g.generate(scale.max(1), &mut rng))
+}
+
+/// Creates a small input for `day`, see [`InputGenerator::generate_small`].
+/// Returns [`None`] if the day has no input generator.
+#[must_use]
+pub fn generate_small(day: Day, seed: u64) -> Option<String> {
+    let mut rng = Rng::new(seed);
+    generator(day).map(|g| g.generate_small(&mut rng))
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{generate, generator, Rng};
+    use crate::all_days;
+
+    #[test]
+    fn rng_is_deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_ne!(Rng::new(1).next_u64(), Rng::new(2).next_u64());
+    }
+
+    #[test]
+    fn rng_range_is_bounded() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            let x = rng.range(10..20);
+            assert!((10..20).contains(&x));
+        }
+    }
+
+    #[test]
+    fn generated_inputs_are_deterministic_and_scale() {
+        for day in all_days() {
+            let Some(small) = generate(day, 1, 1) else {
+                continue;
+            };
+            assert_eq!(generate(day, 1, 1), Some(small.clone()));
+            assert!(!small.is_empty());
+            let large = generate(day, 4, 1).unwrap();
+            if generator(day).unwrap().scales() {
+                assert!(large.len() > 2 * small.len(), "day {day} does not scale");
+            } else {
+                assert_eq!(large.len(), small.len(), "day {day} scales");
+            }
+        }
+    }
+}
@@ -0,0 +1,226 @@
+/// Loads puzzle inputs and examples from the data directory.
+///
+/// Paths are resolved relative to the root of the crate, so tests work no matter which directory
+/// they are run from. Line endings are normalized to `\n` and a leading byte order mark is removed.
+/// With the `embed_inputs` feature, the files are embedded into the binary at build time.
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    ops::Deref,
+    path::{Path, PathBuf},
+};
+
+use crate::template::config;
+use crate::{Day, Part};
+
+#[cfg(feature = "embed_inputs")]
+mod embedded_files {
+    include!(concat!(env!("OUT_DIR"), "/embedded.rs"));
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    IO {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// The file is empty or only contains whitespace, like the files created by `cargo scaffold`.
+    Empty {
+        path: PathBuf,
+        hint: Option<String>,
+    },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::IO { path, source } => {
+                write!(
+                    f,
+                    "could not open input file \"{}\": {source}",
+                    path.display()
+                )
+            }
+            InputError::Empty { path, hint } => {
+                write!(f, "input file \"{}\" is empty", path.display())?;
+                match hint {
+                    Some(hint) => write!(f, ", {hint}"),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl Error for InputError {}
+
+/// The text of an input file, see the module documentation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    text: String,
+}
+
+impl Input {
+    /// Normalizes the text of an input.
+    #[must_use]
+    pub fn new(text: &str) -> Self {
+        let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+        Input {
+            text: text.replace("\r\n", "\n"),
+        }
+    }
+
+    /// Loads `<day>.txt` from a folder of the data directory, e.g. `inputs` or `examples`.
+    pub fn load(folder: &str, day: Day) -> Result<Self, InputError> {
+        Input::load_file(folder, day, &format!("{day}.txt"))
+    }
+
+    /// Loads the file of a part, e.g. `01-2.txt`.
+    pub fn load_part(folder: &str, day: Day, part: Part) -> Result<Self, InputError> {
+        Input::load_file(folder, day, &format!("{day}-{part}.txt"))
+    }
+
+    fn load_file(folder: &str, day: Day, file_name: &str) -> Result<Self, InputError> {
+        let path = resolve(folder).join(file_name);
+
+        let hint = match folder {
+            "inputs" => Some(format!("run `cargo download {day}` to download it")),
+            "examples" => Some("paste the example of the puzzle description into it".into()),
+            _ => None,
+        };
+
+        match embedded(folder, file_name) {
+            Some(text) => Input::check(path, text, hint),
+            None => Input::read(path, hint),
+        }
+    }
+
+    /// Loads a file at a path, e.g. the one passed via `--input`.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, InputError> {
+        Input::read(path.as_ref().to_path_buf(), None)
+    }
+
+    fn read(path: PathBuf, hint: Option<String>) -> Result<Self, InputError> {
+        match fs::read_to_string(&path) {
+            Ok(text) => Input::check(path, &text, hint),
+            Err(source) => Err(InputError::IO { path, source }),
+        }
+    }
+
+    fn check(path: PathBuf, text: &str, hint: Option<String>) -> Result<Self, InputError> {
+        if text.trim().trim_start_matches('\u{feff}').is_empty() {
+            return Err(InputError::Empty { path, hint });
+        }
+
+        Ok(Input::new(text))
+    }
+
+    /// Removes the newlines at the end of the input.
+    #[must_use]
+    pub fn trimmed(mut self) -> Self {
+        let len = self.text.trim_end_matches('\n').len();
+        self.text.truncate(len);
+        self
+    }
+
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.text
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+
+    /// The blocks of the input that are separated by empty lines, without surrounding newlines.
+    pub fn sections(&self) -> impl Iterator<Item = &str> {
+        self.text
+            .split("\n\n")
+            .map(|section| section.trim_matches('\n'))
+            .filter(|section| !section.is_empty())
+    }
+
+    /// The bytes of every line, indexed as `grid[row][col]`.
+    #[must_use]
+    pub fn grid(&self) -> Vec<&[u8]> {
+        self.lines().map(str::as_bytes).collect()
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.text
+    }
+}
+
+/// The text of a file that was embedded with the `embed_inputs` feature, e.g. `("inputs", "01.txt")`.
+#[cfg(feature = "embed_inputs")]
+#[must_use]
+pub fn embedded(folder: &str, file_name: &str) -> Option<&'static str> {
+    embedded_files::file(folder, file_name)
+}
+
+#[cfg(not(feature = "embed_inputs"))]
+#[must_use]
+pub fn embedded(_folder: &str, _file_name: &str) -> Option<&'static str> {
+    None
+}
+
+/// The directory of a folder of the data directory, relative to the root of the crate.
+#[must_use]
+pub fn resolve(folder: &str) -> PathBuf {
+    config::project().paths.dir(folder)
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{Input, InputError};
+    use std::{env, fs};
+
+    #[test]
+    fn normalizes_input() {
+        let input = Input::new("\u{feff}a\r\nb\r\n");
+        assert_eq!(input.as_str(), "a\nb\n");
+        assert_eq!(input.trimmed().as_str(), "a\nb");
+    }
+
+    #[test]
+    fn splits_input() {
+        let input = Input::new("1\n2\n\n3\n\n\n#.\n.#\n");
+
+        assert_eq!(input.lines().count(), 8);
+        assert_eq!(
+            input.sections().collect::<Vec<_>>(),
+            ["1\n2", "3", "#.\n.#"]
+        );
+
+        let input = Input::new("#.\n.#");
+        let grid = input.grid();
+        assert_eq!(grid[1][0], b'.');
+        assert_eq!(grid[1][1], b'#');
+    }
+
+    #[test]
+    fn reports_path_of_missing_or_empty_file() {
+        let path = env::temp_dir().join("aoc-empty-input.txt");
+        fs::write(&path, "\r\n").unwrap();
+
+        let error = Input::from_path(&path).unwrap_err();
+        assert!(matches!(error, InputError::Empty { .. }));
+        assert!(error.to_string().contains("aoc-empty-input.txt"));
+        fs::remove_file(&path).unwrap();
+
+        let error = Input::from_path(&path).unwrap_err();
+        assert!(matches!(error, InputError::IO { .. }));
+        assert!(error.to_string().contains("aoc-empty-input.txt"));
+    }
+}
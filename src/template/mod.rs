@@ -1,48 +1,176 @@
-use crate::Day;
-use std::{env, fs};
+use crate::{Day, Part};
+use input::{Input, InputError};
+use std::{env, process};
 
+pub mod alloc;
+pub mod answers;
 pub mod aoc_cli;
+pub mod bench;
+pub mod benchmark_chart;
 pub mod commands;
+pub mod config;
+pub mod cpu_time;
+pub mod debug;
+pub mod generate;
+pub mod input;
+pub mod profile;
 pub mod readme_benchmarks;
 pub mod runner;
+pub mod verdict;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
-/// Helper function that reads a text file to a string.
+/// Helper function that reads a text file to a string, see [`Input::load`].
+/// Returns an empty string for an empty file, like the example created by `cargo scaffold`,
+/// and panics with the path of the file if it cannot be read.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    or_empty(Input::load(folder, day))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+pub fn read_file_part(folder: &str, day: Day, part: Part) -> String {
+    or_empty(Input::load_part(folder, day, part))
+}
+
+fn or_empty(input: Result<Input, InputError>) -> String {
+    match input {
+        Ok(input) => input.into_string(),
+        Err(InputError::Empty { .. }) => String::new(),
+        Err(e) => panic!("{e}"),
+    }
+}
+
+/// Helper function that collects inputs to compare variants of a part against each other.
+/// These are the example for the part (if not empty) and a few small generated inputs.
+#[must_use]
+pub fn differential_inputs(day: Day, part: Part) -> Vec<String> {
+    let example = Input::load_part("examples", day, part).or_else(|_| Input::load("examples", day));
+
+    let mut inputs: Vec<String> = example.map(Input::into_string).into_iter().collect();
+
+    inputs.extend((0..10).filter_map(|seed| generate::generate_small(day, seed)));
+    inputs
+}
+
+/// Helper function that reads the puzzle input for a day.
+/// Reads the file passed via `--input <path>` instead, if present.
+#[must_use]
+pub fn read_input(day: Day) -> String {
+    let args: Vec<String> = env::args().collect();
+
+    let input = match args.iter().position(|x| x == "--input") {
+        Some(i) => {
+            let filepath = args.get(i + 1).expect("expecting a path after --input");
+            Input::from_path(filepath)
+        }
+        None => Input::load("inputs", day),
+    };
+
+    match input {
+        Ok(input) => input.into_string(),
+        Err(e) => {
+            eprintln!("Could not read the input: {e}");
+            process::exit(1);
+        }
+    }
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
+///
+/// Alternative implementations of a part can be registered as variants, e.g.
+/// `solution!(5, part_two: [part_two_bruteforce])`. Variants are run with
+/// `cargo solve 5 --variant part_two_bruteforce` and a test is generated for each
+/// of them that asserts it agrees with the main implementation.
+///
+/// Puzzles with only one part, like the one of day 25, use `solution!(25, single_part)`,
+/// which does not need a `part_two` function. Variants are registered the same way, e.g.
+/// `solution!(25, single_part, part_one: [part_one_fast])`.
 #[macro_export]
 macro_rules! solution {
+    (@part_two two, $input:expr) => {
+        advent_of_code::template::runner::run_part(part_two, $input, DAY, advent_of_code::part!(2));
+    };
+    (@part_two one, $input:expr) => {
+        advent_of_code::template::runner::run_missing_part(DAY, advent_of_code::part!(2));
+    };
     ($day:expr) => {
+        advent_of_code::solution!($day, part_one: [], part_two: []);
+    };
+    ($day:expr, single_part) => {
+        advent_of_code::solution!(@main $day, one, part_one: [], part_two: []);
+    };
+    ($day:expr, single_part, part_one: [$($one:ident),* $(,)?]) => {
+        advent_of_code::solution!(@main $day, one, part_one: [$($one),*], part_two: []);
+    };
+    ($day:expr, part_one: [$($one:ident),* $(,)?]) => {
+        advent_of_code::solution!($day, part_one: [$($one),*], part_two: []);
+    };
+    ($day:expr, part_two: [$($two:ident),* $(,)?]) => {
+        advent_of_code::solution!($day, part_one: [], part_two: [$($two),*]);
+    };
+    ($day:expr, part_one: [$($one:ident),* $(,)?], part_two: [$($two:ident),* $(,)?]) => {
+        advent_of_code::solution!(@main $day, two, part_one: [$($one),*], part_two: [$($two),*]);
+    };
+    (@main $day:expr, $parts:ident, part_one: [$($one:ident),*], part_two: [$($two:ident),*]) => {
         /// The current day.
         const DAY: advent_of_code::Day = advent_of_code::day!($day);
 
         fn main() {
             use advent_of_code::template::runner::*;
-            let input = advent_of_code::template::read_file("inputs", DAY);
-            run_part(part_one, &input, DAY, 1);
-            run_part(part_two, &input, DAY, 2);
+            configure_threads();
+            configure_verbosity();
+            let input = advent_of_code::template::read_input(DAY);
+
+            let Some(variant) = requested_variant() else {
+                run_part(part_one, &input, DAY, advent_of_code::part!(1));
+                advent_of_code::solution!(@part_two $parts, &input);
+                return;
+            };
+
+            $(if variant == stringify!($one) {
+                return run_variant($one, &input, DAY, advent_of_code::part!(1), &variant);
+            })*
+            $(if variant == stringify!($two) {
+                return run_variant($two, &input, DAY, advent_of_code::part!(2), &variant);
+            })*
+
+            let available: &[&str] = &[$(stringify!($one),)* $(stringify!($two),)*];
+            eprintln!("Unknown variant `{variant}`. Available variants: {available:?}");
+            std::process::exit(1);
+        }
+
+        #[cfg(test)]
+        mod variants {
+            $(
+                #[test]
+                fn $one() {
+                    for input in advent_of_code::template::differential_inputs(super::DAY, advent_of_code::part!(1)) {
+                        assert_eq!(
+                            super::$one(&input),
+                            super::part_one(&input),
+                            "variant `{}` disagrees with `part_one` on input:\n{input}",
+                            stringify!($one),
+                        );
+                    }
+                }
+            )*
+            $(
+                #[test]
+                fn $two() {
+                    for input in advent_of_code::template::differential_inputs(super::DAY, advent_of_code::part!(2)) {
+                        assert_eq!(
+                            super::$two(&input),
+                            super::part_two(&input),
+                            "variant `{}` disagrees with `part_two` on input:\n{input}",
+                            stringify!($two),
+                        );
+                    }
+                }
+            )*
         }
     };
 }
@@ -0,0 +1,248 @@
+/// Timing spans inside solutions, see [`crate::span`].
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// `true` in builds with the `profile` feature. Without it, `span!` only runs its block.
+pub const ENABLED: bool = cfg!(feature = "profile");
+
+/// A span and the time spent in it, summed over all of its calls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub name: &'static str,
+    pub parent: Option<usize>,
+    pub duration: Duration,
+    pub calls: u32,
+}
+
+/// The spans recorded during one run of a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub total: Duration,
+    /// Every node comes after its parent.
+    pub nodes: Vec<Node>,
+}
+
+struct Recorder {
+    started: Instant,
+    nodes: Vec<Node>,
+    /// The indices of the spans that are currently entered.
+    stack: Vec<usize>,
+}
+
+thread_local! {
+    // only the thread that called `start` records, spans on other threads are ignored.
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+    static REPORT: RefCell<Option<Report>> = const { RefCell::new(None) };
+}
+
+/// Starts recording spans on the current thread.
+pub fn start() {
+    RECORDER.with(|recorder| {
+        *recorder.borrow_mut() = Some(Recorder {
+            started: Instant::now(),
+            nodes: vec![],
+            stack: vec![],
+        });
+    });
+}
+
+/// Stops recording and keeps the report for [`take`]. Does nothing if nothing was recorded.
+pub fn stop() {
+    if let Some(recorder) = RECORDER.with(|recorder| recorder.borrow_mut().take()) {
+        let report = Report {
+            total: recorder.started.elapsed(),
+            nodes: recorder.nodes,
+        };
+        REPORT.with(|r| *r.borrow_mut() = Some(report));
+    }
+}
+
+/// Returns the report of the last recording.
+#[must_use]
+pub fn take() -> Option<Report> {
+    REPORT.with(|r| r.borrow_mut().take())
+}
+
+/// Ends a span when dropped.
+pub struct SpanGuard {
+    index: usize,
+    started: Instant,
+}
+
+/// Enters a span, use [`crate::span`] instead. Returns `None` if nothing is recorded.
+#[must_use]
+pub fn enter(name: &'static str) -> Option<SpanGuard> {
+    RECORDER.with(|recorder| {
+        let mut recorder = recorder.borrow_mut();
+        let recorder = recorder.as_mut()?;
+        let parent = recorder.stack.last().copied();
+
+        let index = match recorder
+            .nodes
+            .iter()
+            .position(|node| node.parent == parent && node.name == name)
+        {
+            Some(index) => index,
+            None => {
+                recorder.nodes.push(Node {
+                    name,
+                    parent,
+                    duration: Duration::ZERO,
+                    calls: 0,
+                });
+                recorder.nodes.len() - 1
+            }
+        };
+
+        recorder.stack.push(index);
+
+        Some(SpanGuard {
+            index,
+            started: Instant::now(),
+        })
+    })
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        let elapsed = self.started.elapsed();
+
+        RECORDER.with(|recorder| {
+            if let Some(recorder) = recorder.borrow_mut().as_mut() {
+                let node = &mut recorder.nodes[self.index];
+                node.duration += elapsed;
+                node.calls += 1;
+                recorder.stack.pop();
+            }
+        });
+    }
+}
+
+/// Formats a report as an indented tree with the share of the total time of every span.
+#[must_use]
+pub fn format_report(report: &Report) -> Vec<String> {
+    fn format_children(
+        report: &Report,
+        parent: Option<usize>,
+        depth: usize,
+        lines: &mut Vec<String>,
+    ) {
+        for (index, node) in report.nodes.iter().enumerate() {
+            if node.parent != parent {
+                continue;
+            }
+
+            let share = if report.total.is_zero() {
+                0.0
+            } else {
+                node.duration.as_secs_f64() / report.total.as_secs_f64() * 100.0
+            };
+            let calls = if node.calls > 1 {
+                format!(", {} calls", node.calls)
+            } else {
+                String::new()
+            };
+
+            lines.push(format!(
+                "{}{}: {:.1?} ({share:.1}%{calls})",
+                "  ".repeat(depth + 1),
+                node.name,
+                node.duration
+            ));
+            format_children(report, Some(index), depth + 1, lines);
+        }
+    }
+
+    let mut lines = vec![format!("Total: {:.1?}", report.total)];
+    format_children(report, None, 0, &mut lines);
+    lines
+}
+
+/// Records how long a block takes when the solution runs with `--profile`.
+/// Spans can be nested and spans with the same name under the same parent are summed up.
+/// Only spans on the thread running the part are recorded, not those inside `rayon` iterators.
+///
+/// ```
+/// let ranges = advent_of_code::span!("reduce_ranges", { reduce_ranges(ranges) });
+/// ```
+#[cfg(feature = "profile")]
+#[macro_export]
+macro_rules! span {
+    ($name:literal, $body:block) => {{
+        let _guard = $crate::template::profile::enter($name);
+        $body
+    }};
+}
+
+/// Runs the block, spans are only recorded in builds with the `profile` feature.
+#[cfg(not(feature = "profile"))]
+#[macro_export]
+macro_rules! span {
+    ($name:literal, $body:block) => {
+        $body
+    };
+}
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{enter, format_report, start, stop, take, Node, Report};
+    use std::time::Duration;
+
+    #[test]
+    fn records_nested_spans() {
+        assert!(enter("ignored").is_none());
+
+        start();
+        for _ in 0..3 {
+            let _outer = enter("outer");
+            let _inner = enter("inner");
+        }
+        drop(enter("other"));
+        stop();
+
+        let report = take().unwrap();
+        let summary: Vec<_> = report
+            .nodes
+            .iter()
+            .map(|node| (node.name, node.parent, node.calls))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("outer", None, 3),
+                ("inner", Some(0), 3),
+                ("other", None, 1)
+            ]
+        );
+        assert!(take().is_none());
+    }
+
+    #[test]
+    fn formats_report() {
+        let node = |name, parent, millis, calls| Node {
+            name,
+            parent,
+            duration: Duration::from_millis(millis),
+            calls,
+        };
+
+        let report = Report {
+            total: Duration::from_millis(10),
+            nodes: vec![
+                node("parse", None, 1, 1),
+                node("reduce_ranges", None, 8, 1),
+                node("merge", Some(1), 6, 25),
+            ],
+        };
+
+        assert_eq!(
+            format_report(&report),
+            [
+                "Total: 10.0ms",
+                "  parse: 1.0ms (10.0%)",
+                "  reduce_ranges: 8.0ms (80.0%)",
+                "    merge: 6.0ms (60.0%, 25 calls)",
+            ]
+        );
+    }
+}
@@ -1,10 +1,17 @@
-/// Module that updates the readme me with timing information.
-/// The approach taken is similar to how `aoc-readme-stars` handles this.
+/// Module that updates the readme me with timing information and the stars table.
+/// The approach taken is similar to how `aoc-readme-stars` handles this:
+/// each generated section is enclosed by a pair of marker comments and replaced as a whole.
 use std::{fs, io};
 
-use crate::Day;
+use crate::template::{alloc, commands::scaffold, config};
+use crate::{Day, DaySet};
 
-static MARKER: &str = "<!--- benchmarking table --->";
+/// The default markers, they can be changed in the `[readme]` section of `aoc.toml`.
+pub const MARKER: &str = "<!--- benchmarking table --->";
+pub const STARS_MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// The cell of a part the puzzle does not have.
+const NOT_APPLICABLE: &str = "n/a";
 
 #[derive(Debug)]
 pub enum Error {
@@ -24,6 +31,39 @@ pub struct Timings {
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// The larger peak of live heap bytes of both parts, if allocations were counted.
+    pub peak_memory: Option<u64>,
+}
+
+/// The timings of a day as listed in the readme.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableRow {
+    pub day: Day,
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+    pub peak_memory: Option<u64>,
+}
+
+impl From<TableRow> for Timings {
+    fn from(row: TableRow) -> Self {
+        let nanos = |timing: &Option<String>| timing.as_deref().and_then(parse_duration);
+
+        Timings {
+            day: row.day,
+            total_nanos: nanos(&row.part_1).unwrap_or(0.0) + nanos(&row.part_2).unwrap_or(0.0),
+            part_1: row.part_1,
+            part_2: row.part_2,
+            peak_memory: row.peak_memory,
+        }
+    }
+}
+
+/// The solved parts of a day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stars {
+    pub day: Day,
+    pub part_1: bool,
+    pub part_2: bool,
 }
 
 pub struct TablePosition {
@@ -31,13 +71,21 @@ pub struct TablePosition {
     pos_end: usize,
 }
 
+/// The file with the code of a solution, which the table links to: the module in `src/solutions`
+/// if there is one, the binary otherwise.
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_solution_path(day: Day) -> String {
+    let module_path = scaffold::get_lib_module_path(day);
+    let path = if config::resolve(&module_path).exists() {
+        module_path
+    } else {
+        scaffold::get_module_path(day)
+    };
+    format!("./{path}")
 }
 
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -58,53 +106,217 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Vec<Timings>, total_millis: f64) -> String {
+fn construct_table(
+    marker: &str,
+    prefix: &str,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    chart: Option<&str>,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
+    let mut lines: Vec<String> = vec![marker.into(), header, String::new()];
+
+    if let Some(chart) = chart {
+        lines.push(format!("![Benchmark chart](./{chart})"));
+        lines.push(String::new());
+    }
+
+    let has_memory = timings.iter().any(|t| t.peak_memory.is_some());
+
+    if has_memory {
+        lines.push("| Day | Part 1 | Part 2 | Peak memory |".into());
+        lines.push("| :---: | :---: | :---:  | :---: |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
+
+    for timing in timings {
+        let path = get_solution_path(timing.day);
+        let cell = |timing: Option<String>| format!("`{}`", timing.as_deref().unwrap_or("-"));
+        let part_2 = if timing.day.has_part_two() {
+            cell(timing.part_2)
+        } else {
+            NOT_APPLICABLE.into()
+        };
+
+        let mut line = format!(
+            "| [Day {}]({}) | {} | {part_2} |",
+            timing.day.into_inner(),
+            path,
+            cell(timing.part_1),
+        );
+        if has_memory {
+            let peak = timing.peak_memory.map_or("-".into(), alloc::format_bytes);
+            line.push_str(&format!(" `{peak}` |"));
+        }
+        lines.push(line);
+    }
+
+    lines.push(String::new());
+    lines.push(format!("**Total: {total_millis:.2}ms**"));
+    lines.push(marker.into());
+
+    lines.join("\n")
+}
+
+/// Reads the rows of the benchmark table, see [`construct_table`] for the format.
+fn read_content(s: &str, marker: &str) -> Result<Vec<TableRow>, Error> {
+    let positions = locate_table(s, marker)?;
+
+    let rows = s[positions.pos_start..positions.pos_end]
+        .lines()
+        .filter_map(|line| {
+            let cells: Vec<&str> = line.split('|').map(str::trim).collect();
+            let [_, day, part_1, part_2, ref rest @ ..] = cells[..] else {
+                return None;
+            };
+
+            let day = day.strip_prefix("[Day ")?.split(']').next()?.parse().ok()?;
+            let timing = |cell: &str| match cell.trim_matches('`') {
+                "-" | NOT_APPLICABLE => None,
+                timing => Some(timing.to_string()),
+            };
+
+            // the peak memory column is only present if allocations were counted.
+            let peak_memory = match rest {
+                [peak, _] => alloc::parse_bytes(peak.trim_matches('`')),
+                _ => None,
+            };
+
+            Some(TableRow {
+                day,
+                part_1: timing(part_1),
+                part_2: timing(part_2),
+                peak_memory,
+            })
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+fn construct_stars_table(marker: &str, prefix: &str, stars: Vec<Stars>, year: u16) -> String {
+    let star = |solved: bool| if solved { "⭐" } else { " " };
+
     let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
+        marker.into(),
+        format!("{prefix} {year} Results"),
         String::new(),
         "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
+        "| :---: | :---: | :---: |".into(),
     ];
 
-    for timing in timings {
-        let path = get_path_for_bin(timing.day);
+    for stars in stars.into_iter().filter(|s| s.part_1 || s.part_2) {
+        let day = stars.day.into_inner();
         lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
+            "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {} | {} |",
+            star(stars.part_1),
+            star(stars.part_2)
         ));
     }
 
-    lines.push(String::new());
-    lines.push(format!("**Total: {total_millis:.2}ms**"));
-    lines.push(MARKER.into());
+    lines.push(marker.into());
 
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
-    s.replace_range(positions.pos_start..positions.pos_end, &table);
+/// Replaces the section enclosed by `marker` with `section`, which includes the markers.
+fn update_section(s: &mut String, marker: &str, section: &str) -> Result<(), Error> {
+    let positions = locate_table(s, marker)?;
+    s.replace_range(positions.pos_start..positions.pos_end, section);
     Ok(())
 }
 
-pub fn update(timings: Vec<Timings>, total_millis: f64) -> Result<(), Error> {
-    let path = "README.md";
-    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    update_content(&mut readme, timings, total_millis)?;
-    fs::write(path, &readme)?;
+fn update_content(
+    s: &mut String,
+    marker: &str,
+    timings: Vec<Timings>,
+    total_millis: f64,
+    chart: Option<&str>,
+) -> Result<(), Error> {
+    let table = construct_table(marker, "##", timings, total_millis, chart);
+    update_section(s, marker, &table)
+}
+
+fn update_stars_content(
+    s: &mut String,
+    marker: &str,
+    stars: Vec<Stars>,
+    year: u16,
+) -> Result<(), Error> {
+    update_section(s, marker, &construct_stars_table(marker, "##", stars, year))
+}
+
+fn update_readme(update: impl FnOnce(&mut String) -> Result<(), Error>) -> Result<(), Error> {
+    let path = config::resolve(&config::project().readme.path);
+    let mut readme = String::from_utf8_lossy(&fs::read(&path)?).to_string();
+    update(&mut readme)?;
+    fs::write(&path, &readme)?;
     Ok(())
 }
 
-#[cfg(feature = "test_lib")]
+/// Reads the benchmark table from the readme.
+pub fn read() -> Result<Vec<TableRow>, Error> {
+    let readme = &config::project().readme;
+    let contents = String::from_utf8_lossy(&fs::read(config::resolve(&readme.path))?).to_string();
+    read_content(&contents, &readme.benchmarks_marker)
+}
+
+/// Combines the timings of a run over `days` with the rows of the other days in the readme,
+/// so a partial run does not drop the timings of days it did not run.
+#[must_use]
+pub fn merge(timings: Vec<Timings>, days: &DaySet) -> Vec<Timings> {
+    merge_timings(read().unwrap_or_default(), timings, days)
+}
+
+fn merge_timings(existing: Vec<TableRow>, timings: Vec<Timings>, days: &DaySet) -> Vec<Timings> {
+    let mut merged: Vec<Timings> = existing
+        .into_iter()
+        .filter(|row| !days.contains(row.day))
+        .map(Timings::from)
+        .chain(timings)
+        .collect();
+
+    merged.sort_by_key(|timing| timing.day);
+    merged
+}
+
+fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
+    s.split(postfix).next()?.parse().ok()
+}
+
+/// Parses a formatted [`std::time::Duration`] like `74.13ms` to nanoseconds.
+#[must_use]
+pub fn parse_duration(s: &str) -> Option<f64> {
+    // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
+    match s {
+        s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
+        s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
+        s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
+        s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
+    }
+}
+
+/// Regenerates the benchmark table. If `chart` is set, the table links to the chart at that path.
+pub fn update(timings: Vec<Timings>, total_millis: f64, chart: Option<&str>) -> Result<(), Error> {
+    let marker = &config::project().readme.benchmarks_marker;
+    update_readme(|readme| update_content(readme, marker, timings, total_millis, chart))
+}
+
+/// Regenerates the stars table, linking each day to its puzzle in `year`.
+pub fn update_stars(stars: Vec<Stars>, year: u16) -> Result<(), Error> {
+    let marker = &config::project().readme.stars_marker;
+    update_readme(|readme| update_stars_content(readme, marker, stars, year))
+}
+
+#[cfg(all(test, feature = "test_lib"))]
 mod tests {
-    use super::{update_content, Timings, MARKER};
+    use super::{
+        merge_timings, read_content, update_content, update_stars_content, Stars, TableRow,
+        Timings, MARKER, STARS_MARKER,
+    };
     use crate::day;
 
     fn get_mock_timings() -> Vec<Timings> {
@@ -114,18 +326,21 @@ mod tests {
                 part_1: Some("10ms".into()),
                 part_2: Some("20ms".into()),
                 total_nanos: 3e+10,
+                peak_memory: None,
             },
             Timings {
                 day: day!(2),
                 part_1: Some("30ms".into()),
                 part_2: Some("40ms".into()),
                 total_nanos: 7e+10,
+                peak_memory: None,
             },
             Timings {
                 day: day!(4),
                 part_1: Some("40ms".into()),
                 part_2: Some("50ms".into()),
                 total_nanos: 9e+10,
+                peak_memory: None,
             },
         ]
     }
@@ -134,28 +349,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        assert_eq!(s.contains("## Benchmarks"), true);
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
+        assert!(s.contains("## Benchmarks"));
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -163,7 +378,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -172,9 +387,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/solutions/day01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/solutions/day02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/solutions/day04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -183,4 +398,133 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn reads_benchmarks() {
+        let mut s = format!("foo\n{}{}\nbaz", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings[1].part_2 = None;
+        update_content(&mut s, MARKER, timings, 190.0, None).unwrap();
+
+        let rows = read_content(&s, MARKER).unwrap();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(
+            rows[1],
+            TableRow {
+                day: day!(2),
+                part_1: Some("30ms".into()),
+                part_2: None,
+                peak_memory: None,
+            }
+        );
+        assert_eq!(rows[2].day, day!(4));
+    }
+
+    #[test]
+    fn formats_single_part_day() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        let timings = vec![Timings {
+            day: day!(25),
+            part_1: Some("5ms".into()),
+            part_2: None,
+            total_nanos: 5e6,
+            peak_memory: None,
+        }];
+        update_content(&mut s, MARKER, timings, 5.0, None).unwrap();
+
+        assert!(s.contains("| [Day 25](./src/bin/25.rs) | `5ms` | n/a |"));
+        assert_eq!(read_content(&s, MARKER).unwrap()[0].part_2, None);
+    }
+
+    #[test]
+    fn format_stars() {
+        let mut s = format!("foo\n{STARS_MARKER}\nold\n{STARS_MARKER}\n{MARKER}{MARKER}");
+        let stars = vec![
+            Stars {
+                day: day!(1),
+                part_1: true,
+                part_2: true,
+            },
+            Stars {
+                day: day!(2),
+                part_1: false,
+                part_2: false,
+            },
+            Stars {
+                day: day!(3),
+                part_1: true,
+                part_2: false,
+            },
+        ];
+        update_stars_content(&mut s, STARS_MARKER, stars, 2023).unwrap();
+        let expected = [
+            "foo",
+            "<!--- advent_readme_stars table --->",
+            "## 2023 Results",
+            "",
+            "| Day | Part 1 | Part 2 |",
+            "| :---: | :---: | :---: |",
+            "| [Day 1](https://adventofcode.com/2023/day/1) | ⭐ | ⭐ |",
+            "| [Day 3](https://adventofcode.com/2023/day/3) | ⭐ |   |",
+            "<!--- advent_readme_stars table --->",
+            "<!--- benchmarking table ---><!--- benchmarking table --->",
+        ]
+        .join("\n");
+        assert_eq!(s, expected);
+    }
+
+    #[test]
+    fn links_chart() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        let chart = Some(".assets/benchmarks.svg");
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, chart).unwrap();
+        assert!(
+            s.contains("## Benchmarks\n\n![Benchmark chart](./.assets/benchmarks.svg)\n\n| Day")
+        );
+        assert_eq!(read_content(&s, MARKER).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn format_peak_memory() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        let mut timings = get_mock_timings();
+        timings[0].peak_memory = Some(1536);
+        update_content(&mut s, MARKER, timings, 190.0, None).unwrap();
+        assert!(s.contains("| Day | Part 1 | Part 2 | Peak memory |"));
+        assert!(s.contains("| [Day 1](./src/solutions/day01.rs) | `10ms` | `20ms` | `1.5 KiB` |"));
+        assert!(s.contains("| [Day 2](./src/solutions/day02.rs) | `30ms` | `40ms` | `-` |"));
+        let rows = read_content(&s, MARKER).unwrap();
+        assert_eq!(rows[0].part_2, Some("20ms".into()));
+        assert_eq!(rows[0].peak_memory, Some(1536));
+        assert_eq!(rows[1].peak_memory, None);
+    }
+
+    #[test]
+    fn merges_partial_runs() {
+        let mut s = format!("{}{}", MARKER, MARKER);
+        update_content(&mut s, MARKER, get_mock_timings(), 190.0, None).unwrap();
+
+        let rerun = vec![Timings {
+            day: day!(2),
+            part_1: Some("1ms".into()),
+            part_2: None,
+            total_nanos: 1e6,
+            peak_memory: None,
+        }];
+        let days = "2-3".parse().unwrap();
+        let merged = merge_timings(read_content(&s, MARKER).unwrap(), rerun, &days);
+
+        let summary: Vec<_> = merged
+            .iter()
+            .map(|t| (t.day.into_inner(), t.part_1.clone(), t.total_nanos))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, Some("10ms".into()), 3e7),
+                (2, Some("1ms".into()), 1e6),
+                (4, Some("40ms".into()), 9e7),
+            ]
+        );
+    }
 }
@@ -1,79 +1,445 @@
 /// Encapsulates code that interacts with solution functions.
-use crate::template::{aoc_cli, ANSI_ITALIC, ANSI_RESET};
-use crate::Day;
+use crate::template::alloc::{self, AllocStats};
+use crate::template::bench::{self, BenchOptions, BenchSettings};
+use crate::template::config;
+use crate::template::cpu_time;
+use crate::template::debug;
+use crate::template::profile;
+use crate::template::verdict::SubmissionVerdict;
+use crate::template::{answers, aoc_cli, commands::stars, ANSI_ITALIC, ANSI_RESET};
+use crate::{ocr, Answer, Day, Part};
 use std::fmt::Display;
 use std::io::{stdout, Write};
-use std::process::Output;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{env, process, thread};
 
 use super::ANSI_BOLD;
 
-pub fn run_part<I: Clone, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
-    let part_str = format!("Part {part}");
+pub fn run_part<I: Clone + Send + Sync, T: Into<Answer> + Send>(
+    func: impl Fn(I) -> Option<T> + Sync,
+    input: I,
+    day: Day,
+    part: Part,
+) {
+    run_labeled(func, input, day, part, format!("Part {part}"));
+}
+
+/// Reports a part the puzzle does not have, see the single-part form of [`crate::solution`].
+pub fn run_missing_part(day: Day, part: Part) {
+    println!("Part {part}: {ANSI_ITALIC}n/a{ANSI_RESET}");
+    refuse_submission(day, part);
+}
+
+/// Runs an alternative implementation of a part, see [`crate::solution`].
+pub fn run_variant<I: Clone + Send + Sync, T: Into<Answer> + Send>(
+    func: impl Fn(I) -> Option<T> + Sync,
+    input: I,
+    day: Day,
+    part: Part,
+    name: &str,
+) {
+    run_labeled(func, input, day, part, format!("Part {part} ({name})"));
+}
+
+/// Returns the name passed via `--variant <name>`, if any.
+#[must_use]
+pub fn requested_variant() -> Option<String> {
+    let args: Vec<String> = env::args().collect();
+    let index = args.iter().position(|x| x == "--variant")?;
+
+    match args.get(index + 1) {
+        Some(name) => Some(name.clone()),
+        None => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 --variant <name>");
+            process::exit(1);
+        }
+    }
+}
+
+/// Returns the number passed via `--threads <n>`, if any.
+#[must_use]
+pub fn requested_threads() -> Option<usize> {
+    requested_positive("--threads", "thread count")
+}
+
+/// Returns the number passed via `--check-determinism <k>`, if any.
+#[must_use]
+pub fn requested_determinism_runs() -> Option<usize> {
+    requested_positive("--check-determinism", "run count")
+}
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+/// Returns the positive integer passed after `flag`, if any, and exits if it is invalid.
+fn requested_positive(flag: &str, name: &str) -> Option<usize> {
+    let args: Vec<String> = env::args().collect();
+    let index = args.iter().position(|x| x == flag)?;
+
+    match args.get(index + 1).map(|n| bench::parse_positive(name, n)) {
+        Some(Ok(n)) => Some(n),
+        Some(Err(e)) => {
+            eprintln!("{e}");
+            process::exit(1);
+        }
+        None => {
+            eprintln!("Unexpected command-line input. Format: cargo solve 1 {flag} <n>");
+            process::exit(1);
+        }
+    }
+}
+
+/// Sizes the global rayon thread pool if `--threads <n>` was passed.
+pub fn configure_threads() {
+    if let Some(threads) = requested_threads() {
+        if let Err(e) = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+        {
+            eprintln!("Could not configure the thread pool: {e}");
+        }
+    }
+}
+
+/// The bench settings from `--bench-time`, `--min-samples`, `--max-samples`, `--warmup` and `aoc.toml`.
+#[must_use]
+pub fn bench_settings() -> BenchSettings {
+    let settings =
+        BenchOptions::from_env().and_then(|options| options.settings(&config::project().config));
+
+    settings.unwrap_or_else(|e| {
+        eprintln!("Invalid bench settings: {e}");
+        process::exit(1);
+    })
+}
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+/// Shows `aoc_debug!` output for `-v`, `-vv`, ... or the same number of `--verbose` flags.
+pub fn configure_verbosity() {
+    let verbosity: usize = env::args()
+        .map(|arg| match arg.as_str() {
+            "--verbose" => 1,
+            arg if arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v') => {
+                arg.len() - 1
+            }
+            _ => 0,
+        })
+        .sum();
+
+    debug::set_verbosity(u8::try_from(verbosity).unwrap_or(u8::MAX));
+}
+
+fn run_labeled<I: Clone + Send + Sync, T: Into<Answer> + Send>(
+    func: impl Fn(I) -> Option<T> + Sync,
+    input: I,
+    day: Day,
+    part: Part,
+    part_str: String,
+) {
+    let func = |input| func(input).map(Into::into);
+
+    if env::args().any(|x| x == "--scaling") {
+        run_scaling(func, input, &part_str);
+        return;
+    }
+
+    let is_profiled = env::args().any(|x| x == "--profile");
+    if is_profiled {
+        if !profile::ENABLED {
+            eprintln!("Spans are only recorded with the `profile` feature, run `cargo solve {day} --profile`.");
+        }
+        profile::start();
+    }
+
+    let (result, timing, alloc_stats) = run_timed(func, input.clone(), |result| {
+        print_result(result, &part_str, "");
+    });
+
+    // taken before the determinism runs, which would record spans as well.
+    let report = is_profiled
+        .then(profile::take)
+        .flatten()
+        .filter(|report| !report.nodes.is_empty());
+
+    let determinism =
+        requested_determinism_runs().and_then(|runs| check_determinism(func, input, runs));
+
+    let mut stats_str = format_duration(&timing.duration, timing.samples);
+    if let Some(cold) = timing.cold {
+        stats_str.push_str(&format!(" [cold {cold:.1?}]"));
+    }
+    if let Some(cpu_time) = timing.cpu_time {
+        stats_str.push_str(&format_cpu_time(cpu_time, timing.parallelism));
+    }
+    if let Some(alloc_stats) = alloc_stats {
+        stats_str.push_str(&format_alloc_stats(&alloc_stats));
+    }
+    if let Some(determinism) = &determinism {
+        stats_str.push_str(&format!(" [{determinism}]"));
+    }
+
+    print_result(&result, &part_str, &stats_str);
+
+    if let Some(determinism) = determinism.filter(|d| !d.is_deterministic()) {
+        for (answer, answer_runs) in determinism.answers {
+            let answer = answer.map_or("✖".to_string(), |a| a.to_string());
+            let answer_runs: Vec<String> = answer_runs.iter().map(ToString::to_string).collect();
+            println!("  {answer} (runs {})", answer_runs.join(", "));
+        }
+    }
+
+    if let Some(report) = report {
+        profile::format_report(&report)
+            .iter()
+            .for_each(|line| println!("{ANSI_ITALIC}{line}{ANSI_RESET}"));
+    }
 
     if let Some(result) = result {
+        check_result(&result, day, part);
         submit_result(result, day, part);
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Warns if a result differs from the known correct answer in `data/answers`.
+fn check_result(result: &Answer, day: Day, part: Part) {
+    if let Some(expected) = answers::read(day, part) {
+        if &expected != result {
+            println!(
+                "{ANSI_ITALIC}Part {part} differs from the stored answer: {expected}{ANSI_RESET}"
+            );
+        }
+    }
+}
+
+/// Benchmarks a part with 1 up to `--threads <n>` (default: all cores) threads
+/// and prints the speedup over the single-threaded run.
+fn run_scaling<I: Clone + Send + Sync, T: Send>(
+    func: impl Fn(I) -> Option<T> + Sync,
+    input: I,
+    part_str: &str,
+) {
+    let max_threads = requested_threads()
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get));
+
+    println!("{ANSI_BOLD}{part_str}{ANSI_RESET}");
+    println!("| Threads | Time | Speedup | Efficiency |");
+
+    let settings = bench_settings();
+    let mut single_threaded: Option<Duration> = None;
+
+    for threads in 1..=max_threads {
+        let pool = match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+            Ok(pool) => pool,
+            Err(e) => {
+                eprintln!("Could not create a thread pool with {threads} threads: {e}");
+                return;
+            }
+        };
+
+        let timing = debug::muted(|| {
+            pool.install(|| {
+                let timer = Instant::now();
+                let result = func(input.clone());
+                let base_time = timer.elapsed();
+                result.map(|_| bench_samples(&func, input.clone(), &base_time, &settings))
+            })
+        });
+
+        let Some(timing) = timing else {
+            println!("| {threads} | ✖ | - | - |");
+            return;
+        };
+
+        let base = *single_threaded.get_or_insert(timing.duration);
+        let speedup = base.as_secs_f64() / timing.duration.as_secs_f64();
+        #[allow(clippy::cast_precision_loss)]
+        let efficiency = speedup / threads as f64 * 100.0;
+        println!(
+            "| {threads} | {:.1?} | {speedup:.2}x | {efficiency:.0}% |",
+            timing.duration
+        );
+    }
+}
+
+/// The answers of the runs of `--check-determinism`.
+struct Determinism {
+    runs: usize,
+    /// distinct answers with the runs that gave them, in order of appearance.
+    answers: Vec<(Option<Answer>, Vec<usize>)>,
+}
+
+impl Determinism {
+    fn is_deterministic(&self) -> bool {
+        self.answers.len() == 1
+    }
+}
+
+impl Display for Determinism {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_deterministic() {
+            write!(f, "✔ deterministic over {} runs", self.runs)
+        } else {
+            write!(
+                f,
+                "✖ nondeterministic, {} distinct answers over {} runs",
+                self.answers.len(),
+                self.runs
+            )
+        }
+    }
+}
+
+/// Runs a part `runs` times more and collects the answers, to check that all runs agree.
+/// Without `--threads <n>`, the runs cycle through rayon pools of 1 up to all cores (at least 2) threads.
+/// Every `HashMap` with the default `RandomState` hasher gets its own random seed, so iteration order differs between runs as well.
+fn check_determinism<I: Clone + Send + Sync>(
+    func: impl Fn(I) -> Option<Answer> + Sync,
+    input: I,
+    runs: usize,
+) -> Option<Determinism> {
+    let thread_counts: Vec<usize> = match requested_threads() {
+        Some(threads) => vec![threads],
+        None => {
+            let cores = thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+            (1..=cores.max(2)).collect()
+        }
+    };
+
+    let mut answers: Vec<(Option<Answer>, Vec<usize>)> = vec![];
+
+    for run in 1..=runs {
+        let threads = thread_counts[(run - 1) % thread_counts.len()];
+        let pool = match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+            Ok(pool) => pool,
+            Err(e) => {
+                eprintln!("Could not create a thread pool with {threads} threads: {e}");
+                return None;
+            }
+        };
+
+        // the part already printed its debug output in the regular run.
+        let answer = debug::muted(|| pool.install(|| func(input.clone())));
+
+        match answers.iter_mut().find(|(a, _)| *a == answer) {
+            Some((_, answer_runs)) => answer_runs.push(run),
+            None => answers.push((answer, vec![run])),
+        }
+    }
+
+    Some(Determinism { runs, answers })
+}
+
+/// The result of benching a part.
+struct Timing {
+    /// The average wall time of a sample.
+    duration: Duration,
+    samples: u128,
+    /// The wall time of the first run, which is not part of the samples.
+    cold: Option<Duration>,
+    /// The average CPU time of a sample, summed over all threads.
+    cpu_time: Option<Duration>,
+    /// CPU time divided by wall time, i.e. the average number of busy cores.
+    parallelism: f64,
+}
+
+/// Run a solution part. The behavior differs depending on whether `--time` was passed:
+///  1. without it, the function is executed once.
+///  2. with it, the function is benched after the first run (approx. 1 second of execution time or 10 samples, whatever take longer, see [`BenchSettings`].)
+///
+/// Allocations are counted during the first execution if the `count_allocations` feature is enabled.
 fn run_timed<I: Clone, T>(
     func: impl Fn(I) -> T,
     input: I,
     hook: impl Fn(&T),
-) -> (T, Duration, u128) {
-    let timer = Instant::now();
-    let result = func(input.clone());
-    let base_time = timer.elapsed();
+) -> (T, Timing, Option<AllocStats>) {
+    let cloned = input.clone();
+    let ((result, base_time), alloc_stats) = alloc::measure(|| {
+        let timer = Instant::now();
+        let result = func(cloned);
+        (result, timer.elapsed())
+    });
+
+    // spans are only recorded during the first run.
+    profile::stop();
 
     hook(&result);
 
-    let run = if std::env::args().any(|x| x == "--time") {
-        bench(func, input, &base_time)
+    let timing = if std::env::args().any(|x| x == "--time") {
+        let settings = bench_settings();
+        debug::muted(|| bench(func, input, &base_time, &settings))
     } else {
-        (base_time, 1)
+        // a single run is too short to measure CPU time reliably.
+        Timing {
+            duration: base_time,
+            samples: 1,
+            cold: None,
+            cpu_time: None,
+            parallelism: 1.0,
+        }
     };
 
-    (result, run.0, run.1)
+    (result, timing, alloc_stats)
 }
 
-fn bench<I: Clone, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
+fn bench<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    settings: &BenchSettings,
+) -> Timing {
     let mut stdout = stdout();
 
     print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
     let _ = stdout.flush();
 
-    let bench_iterations = cmp::min(
-        10000,
-        cmp::max(
-            Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10),
-            10,
-        ),
-    );
-
-    let mut timers: Vec<Duration> = vec![];
+    bench_samples(func, input, base_time, settings)
+}
 
-    for _ in 0..bench_iterations {
-        // need a clone here to make the borrow checker happy.
+/// Times the function after `base_time` measured its first, cold run.
+/// Runs that take nanoseconds are timed in batches and each sample is the average of its batch.
+fn bench_samples<I: Clone, T>(
+    func: impl Fn(I) -> T,
+    input: I,
+    base_time: &Duration,
+    settings: &BenchSettings,
+) -> Timing {
+    let mut run_time = *base_time;
+    for _ in 0..settings.warmup {
         let cloned = input.clone();
         let timer = Instant::now();
         func(cloned);
-        timers.push(timer.elapsed());
+        run_time = timer.elapsed();
     }
 
-    (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
-        bench_iterations,
-    )
+    let plan = bench::plan(settings, run_time);
+    let batch_size = u32::try_from(plan.batch_size).unwrap_or(u32::MAX);
+
+    let mut timers: Vec<Duration> = vec![];
+
+    let cpu_start = cpu_time::process_cpu_time();
+    let wall_start = Instant::now();
+
+    for _ in 0..plan.batches {
+        // clone the inputs up front, so the clones are not timed.
+        let inputs: Vec<I> = (0..batch_size).map(|_| input.clone()).collect();
+        let timer = Instant::now();
+        for cloned in inputs {
+            func(cloned);
+        }
+        timers.push(timer.elapsed() / batch_size);
+    }
+
+    // both include the clones of the input, so their ratio is not skewed by them.
+    let wall_time = wall_start.elapsed();
+    let cpu_time = cpu_start
+        .zip(cpu_time::process_cpu_time())
+        .map(|(start, end)| end.saturating_sub(start));
+    let samples = plan.samples();
+
+    #[allow(clippy::cast_possible_truncation)]
+    Timing {
+        duration: Duration::from_nanos(average_duration(&timers) as u64),
+        samples,
+        cold: Some(*base_time),
+        cpu_time: cpu_time.map(|t| t.div_f64(samples as f64)),
+        parallelism: cpu_time.map_or(1.0, |t| t.as_secs_f64() / wall_time.as_secs_f64()),
+    }
 }
 
 fn average_duration(numbers: &[Duration]) -> u128 {
@@ -92,12 +458,25 @@ fn format_duration(duration: &Duration, samples: u128) -> String {
     }
 }
 
-fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str) {
+fn format_cpu_time(cpu_time: Duration, parallelism: f64) -> String {
+    format!(" [cpu {cpu_time:.1?}, {parallelism:.2}x parallel]")
+}
+
+fn format_alloc_stats(stats: &AllocStats) -> String {
+    format!(
+        " [{} allocs, {} allocated, peak {}]",
+        stats.allocations,
+        alloc::format_bytes(stats.allocated_bytes),
+        alloc::format_bytes(stats.peak_live_bytes)
+    )
+}
+
+fn print_result(result: &Option<Answer>, part: &str, duration_str: &str) {
     let is_intermediate_result = duration_str.is_empty();
 
     match result {
         Some(result) => {
-            if result.to_string().contains('\n') {
+            if result.is_multiline() {
                 let str = format!("{part}: ▼ {duration_str}");
                 if is_intermediate_result {
                     print!("{str}");
@@ -130,11 +509,9 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
 ///  2. aoc-cli is installed.
-fn submit_result<T: Display>(
-    result: T,
-    day: Day,
-    part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+///
+/// With `--wait`, a submission that hits the cooldown is retried once after waiting.
+fn submit_result(result: Answer, day: Day, part: Part) -> Option<SubmissionVerdict> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -148,11 +525,18 @@ fn submit_result<T: Display>(
 
     let part_index = args.iter().position(|x| x == "--submit").unwrap() + 1;
 
-    let Ok(part_submit) = args[part_index].parse::<u8>() else {
-        eprintln!("Unexpected command-line input. Format: cargo solve 1 --submit 1");
-        process::exit(1);
+    let part_submit = match args[part_index].parse::<Part>() {
+        Ok(part_submit) => part_submit,
+        Err(e) => {
+            eprintln!("Invalid value `{}` for --submit: {e}", args[part_index]);
+            process::exit(1);
+        }
     };
 
+    if part_submit == Part::Two && !day.has_part_two() {
+        refuse_submission(day, Part::Two);
+    }
+
     if part_submit != part {
         return None;
     }
@@ -162,6 +546,70 @@ fn submit_result<T: Display>(
         process::exit(1);
     }
 
+    // block letters are submitted as the text they spell.
+    let result = if result.is_multiline() {
+        match ocr::recognize(&result.to_string()) {
+            Ok(text) => {
+                println!("Recognized \"{text}\" in the answer.");
+                Answer::Text(text)
+            }
+            Err(e) => {
+                eprintln!("Could not read the answer: {e}");
+                process::exit(1);
+            }
+        }
+    } else {
+        result
+    };
+
+    let mut verdict = submit_once(&result, day, part)?;
+
+    if let SubmissionVerdict::Cooldown { remaining } = verdict {
+        if args.contains(&"--wait".into()) {
+            println!("Waiting {}s before retrying...", remaining.as_secs());
+            // the cooldown is only reported in whole seconds.
+            thread::sleep(remaining + Duration::from_secs(1));
+            verdict = submit_once(&result, day, part)?;
+        }
+    }
+
+    Some(verdict)
+}
+
+/// Exits if `--submit` asks to submit a part the puzzle does not have.
+fn refuse_submission(day: Day, part: Part) {
+    let args: Vec<String> = env::args().collect();
+    let index = args.iter().position(|x| x == "--submit");
+
+    if index.and_then(|i| args.get(i + 1)?.parse().ok()) == Some(part) {
+        eprintln!("Day {day} only has one part, there is no part {part} to submit.");
+        process::exit(1);
+    }
+}
+
+fn submit_once(result: &Answer, day: Day, part: Part) -> Option<SubmissionVerdict> {
     println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+
+    match aoc_cli::submit(day, part, result) {
+        Ok(Some(verdict)) => {
+            println!("{ANSI_BOLD}Verdict: {verdict}{ANSI_RESET}");
+            if verdict == SubmissionVerdict::Correct {
+                if let Err(e) = answers::write(day, part, result) {
+                    eprintln!("Could not store the answer: {e}");
+                }
+                if let Err(e) = stars::update() {
+                    eprintln!("Could not update the stars in the readme: {e}");
+                }
+            }
+            Some(verdict)
+        }
+        Ok(None) => {
+            eprintln!("Could not recognize the response to the submission.");
+            None
+        }
+        Err(e) => {
+            eprintln!("Could not submit the answer: {e}");
+            None
+        }
+    }
 }
@@ -0,0 +1,137 @@
+/// Interprets the response to a submitted answer, as printed by aoc-cli.
+use std::fmt::Display;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionVerdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in which direction.
+    Wrong,
+    /// An answer was submitted too recently, the next one is accepted after `remaining`.
+    Cooldown {
+        remaining: Duration,
+    },
+    /// The part is already solved, the answer was not checked.
+    AlreadySolved,
+}
+
+impl SubmissionVerdict {
+    /// Parses the text of a response. Returns `None` if the response is not recognized.
+    #[must_use]
+    pub fn parse(response: &str) -> Option<Self> {
+        // aoc-cli wraps long lines, so phrases can span line breaks.
+        let response = response.split_whitespace().collect::<Vec<_>>().join(" ");
+
+        if response.contains("That's the right answer") {
+            Some(SubmissionVerdict::Correct)
+        } else if response.contains("your answer is too high") {
+            Some(SubmissionVerdict::TooHigh)
+        } else if response.contains("your answer is too low") {
+            Some(SubmissionVerdict::TooLow)
+        } else if response.contains("That's not the right answer") {
+            Some(SubmissionVerdict::Wrong)
+        } else if response.contains("You gave an answer too recently") {
+            let remaining = parse_remaining(&response)?;
+            Some(SubmissionVerdict::Cooldown { remaining })
+        } else if response.contains("Did you already complete it?") {
+            Some(SubmissionVerdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses the wait time from "You have 4m 32s left to wait".
+fn parse_remaining(response: &str) -> Option<Duration> {
+    let start = response.find("You have ")? + "You have ".len();
+    let end = start + response[start..].find(" left to wait")?;
+
+    response[start..end]
+        .split(' ')
+        .map(|token| {
+            let unit_start = token.find(|c: char| !c.is_ascii_digit())?;
+            let value: u64 = token[..unit_start].parse().ok()?;
+            match &token[unit_start..] {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+impl Display for SubmissionVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SubmissionVerdict::Correct => write!(f, "correct ⭐"),
+            SubmissionVerdict::TooHigh => write!(f, "wrong, the answer is too high"),
+            SubmissionVerdict::TooLow => write!(f, "wrong, the answer is too low"),
+            SubmissionVerdict::Wrong => write!(f, "wrong"),
+            SubmissionVerdict::Cooldown { remaining } => write!(
+                f,
+                "not checked, an answer was submitted too recently ({}s left to wait)",
+                remaining.as_secs()
+            ),
+            SubmissionVerdict::AlreadySolved => {
+                write!(f, "not checked, the part is already solved")
+            }
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use std::time::Duration;
+
+    use super::SubmissionVerdict;
+
+    fn parse_fixture(name: &str) -> Option<SubmissionVerdict> {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join(format!("src/template/fixtures/submit/{name}.txt"));
+        SubmissionVerdict::parse(&std::fs::read_to_string(path).unwrap())
+    }
+
+    #[test]
+    fn parses_verdicts() {
+        assert_eq!(parse_fixture("correct"), Some(SubmissionVerdict::Correct));
+        assert_eq!(parse_fixture("too_high"), Some(SubmissionVerdict::TooHigh));
+        assert_eq!(parse_fixture("too_low"), Some(SubmissionVerdict::TooLow));
+        assert_eq!(parse_fixture("wrong"), Some(SubmissionVerdict::Wrong));
+        assert_eq!(
+            parse_fixture("already_solved"),
+            Some(SubmissionVerdict::AlreadySolved)
+        );
+    }
+
+    #[test]
+    fn parses_cooldowns() {
+        assert_eq!(
+            parse_fixture("cooldown"),
+            Some(SubmissionVerdict::Cooldown {
+                remaining: Duration::from_secs(272)
+            })
+        );
+        assert_eq!(
+            parse_fixture("cooldown_seconds"),
+            Some(SubmissionVerdict::Cooldown {
+                remaining: Duration::from_secs(45)
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_responses() {
+        assert_eq!(SubmissionVerdict::parse(""), None);
+        assert_eq!(SubmissionVerdict::parse("500 Internal Server Error"), None);
+        assert_eq!(
+            SubmissionVerdict::parse("You gave an answer too recently."),
+            None
+        );
+    }
+}
@@ -0,0 +1,477 @@
+/// A lightweight property-testing toolkit for use in `#[cfg(test)]` blocks.
+///
+/// Values are created by [`Strategy`] implementations from a deterministic [`Rng`],
+/// so a failing property fails the same way on every run. When a property fails,
+/// the input is shrunk to a simpler value that still fails before panicking.
+///
+/// ```
+/// use advent_of_code::testing::{check, int, vec};
+///
+/// check(&vec(int(0..=100_u32), 0..=10), |v| v.iter().sum::<u32>() <= 1000);
+/// ```
+use std::fmt::Debug;
+use std::ops::{Range, RangeInclusive};
+
+pub use crate::template::generate::Rng;
+
+/// Creates random values of a type and knows how to simplify them.
+pub trait Strategy {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Returns simpler candidates for `value`, simplest first.
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+/// Controls how a property is checked.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// Number of random inputs to check.
+    pub cases: usize,
+    pub seed: u64,
+    /// Upper bound for the number of shrinking steps after a failure.
+    pub max_shrinks: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            cases: 256,
+            seed: 0x5EED,
+            max_shrinks: 1024,
+        }
+    }
+}
+
+impl Config {
+    /// Checks `property` against `cases` inputs created by `strategy`.
+    /// Panics with a shrunk counterexample if the property does not hold.
+    pub fn check<S: Strategy>(&self, strategy: &S, property: impl Fn(&S::Value) -> bool) {
+        let mut rng = Rng::new(self.seed);
+
+        for case in 0..self.cases {
+            let value = strategy.generate(&mut rng);
+            if property(&value) {
+                continue;
+            }
+
+            let (shrunk, steps) = self.shrink(strategy, value.clone(), &property);
+            panic!(
+                "property failed on case {case} (seed {:#x}).\nminimal input ({steps} shrinks): {shrunk:?}\noriginal input: {value:?}",
+                self.seed
+            );
+        }
+    }
+
+    fn shrink<S: Strategy>(
+        &self,
+        strategy: &S,
+        mut value: S::Value,
+        property: &impl Fn(&S::Value) -> bool,
+    ) -> (S::Value, usize) {
+        let mut steps = 0;
+
+        'outer: while steps < self.max_shrinks {
+            for candidate in strategy.shrink(&value) {
+                if !property(&candidate) {
+                    value = candidate;
+                    steps += 1;
+                    continue 'outer;
+                }
+            }
+            break;
+        }
+
+        (value, steps)
+    }
+}
+
+/// Checks `property` with the default [`Config`].
+pub fn check<S: Strategy>(strategy: &S, property: impl Fn(&S::Value) -> bool) {
+    Config::default().check(strategy, property);
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Integer types that can be generated by [`int`].
+pub trait Int: Copy + Debug + Ord {
+    fn to_i128(self) -> i128;
+    fn from_i128(value: i128) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(impl Int for $t {
+            fn to_i128(self) -> i128 {
+                self as i128
+            }
+
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn from_i128(value: i128) -> Self {
+                value as $t
+            }
+        })*
+    };
+}
+
+impl_int!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+fn int_in(rng: &mut Rng, lo: i128, hi: i128) -> i128 {
+    #[allow(clippy::cast_sign_loss)]
+    let span = (hi - lo) as u128 + 1;
+    let sample = (u128::from(rng.next_u64()) << 64) | u128::from(rng.next_u64());
+    #[allow(clippy::cast_possible_wrap)]
+    let offset = (sample % span) as i128;
+    lo + offset
+}
+
+/// Candidates between `target` and `value`, closest to `target` first.
+fn shrink_towards(value: i128, target: i128) -> Vec<i128> {
+    let mut candidates = vec![];
+    let mut distance = value - target;
+
+    while distance != 0 {
+        candidates.push(value - distance);
+        distance /= 2;
+    }
+
+    candidates
+}
+
+/// Integers in a range, shrinking towards zero (or the bound closest to it).
+#[derive(Debug, Clone)]
+pub struct Ints<T> {
+    lo: T,
+    hi: T,
+}
+
+/// Creates integers in `range`.
+pub fn int<T: Int>(range: RangeInclusive<T>) -> Ints<T> {
+    let (lo, hi) = range.into_inner();
+    assert!(lo <= hi, "cannot generate integers from an empty range");
+    Ints { lo, hi }
+}
+
+impl<T: Int> Strategy for Ints<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        T::from_i128(int_in(rng, self.lo.to_i128(), self.hi.to_i128()))
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        let target = 0.clamp(self.lo.to_i128(), self.hi.to_i128());
+        shrink_towards(value.to_i128(), target)
+            .into_iter()
+            .map(T::from_i128)
+            .collect()
+    }
+}
+
+/// One of a fixed set of values, shrinking towards the first one.
+#[derive(Debug, Clone)]
+pub struct OneOf<T> {
+    items: Vec<T>,
+}
+
+/// Picks values from `items`.
+pub fn one_of<T: Clone + Debug>(items: &[T]) -> OneOf<T> {
+    assert!(!items.is_empty(), "cannot pick a value from an empty list");
+    OneOf {
+        items: items.to_vec(),
+    }
+}
+
+impl<T: Clone + Debug + PartialEq> Strategy for OneOf<T> {
+    type Value = T;
+
+    fn generate(&self, rng: &mut Rng) -> T {
+        rng.choose(&self.items).clone()
+    }
+
+    fn shrink(&self, value: &T) -> Vec<T> {
+        let index = self.items.iter().position(|x| x == value).unwrap_or(0);
+        self.items[..index].to_vec()
+    }
+}
+
+/// Vectors with a length in a range, shrinking by removing and then simplifying elements.
+#[derive(Debug, Clone)]
+pub struct Vecs<S> {
+    element: S,
+    min_len: usize,
+    max_len: usize,
+}
+
+/// Creates vectors of values from `element` with a length in `len`.
+pub fn vec<S: Strategy>(element: S, len: RangeInclusive<usize>) -> Vecs<S> {
+    let (min_len, max_len) = len.into_inner();
+    assert!(
+        min_len <= max_len,
+        "cannot generate vectors from an empty range"
+    );
+    Vecs {
+        element,
+        min_len,
+        max_len,
+    }
+}
+
+impl<S: Strategy> Strategy for Vecs<S> {
+    type Value = Vec<S::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        #[allow(clippy::cast_possible_truncation)]
+        let len = int_in(rng, self.min_len as i128, self.max_len as i128) as usize;
+        (0..len).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let mut candidates = vec![];
+
+        // drop the tail, then single elements.
+        if value.len() > self.min_len {
+            let half = (value.len() / 2).max(self.min_len);
+            if half < value.len() - 1 {
+                candidates.push(value[..half].to_vec());
+            }
+            for i in 0..value.len() {
+                let mut candidate = value.clone();
+                candidate.remove(i);
+                candidates.push(candidate);
+            }
+        }
+
+        for (i, element) in value.iter().enumerate() {
+            for shrunk in self.element.shrink(element) {
+                let mut candidate = value.clone();
+                candidate[i] = shrunk;
+                candidates.push(candidate);
+            }
+        }
+
+        candidates
+    }
+}
+
+/// Half-open ranges inside of bounds, shrinking towards short ranges close to the lower bound.
+#[derive(Debug, Clone)]
+pub struct Ranges<T> {
+    lo: T,
+    hi: T,
+    max_len: T,
+}
+
+/// Creates ranges `start..end` with `bounds.start() <= start <= end <= bounds.end()`
+/// and a length of at most `max_len`.
+pub fn range<T: Int>(bounds: RangeInclusive<T>, max_len: T) -> Ranges<T> {
+    let (lo, hi) = bounds.into_inner();
+    assert!(lo <= hi, "cannot generate ranges in empty bounds");
+    Ranges { lo, hi, max_len }
+}
+
+impl<T: Int> Strategy for Ranges<T> {
+    type Value = Range<T>;
+
+    fn generate(&self, rng: &mut Rng) -> Range<T> {
+        let (lo, hi) = (self.lo.to_i128(), self.hi.to_i128());
+        let start = int_in(rng, lo, hi);
+        let len = int_in(rng, 0, self.max_len.to_i128().min(hi - start));
+        T::from_i128(start)..T::from_i128(start + len)
+    }
+
+    fn shrink(&self, value: &Range<T>) -> Vec<Range<T>> {
+        let (start, end) = (value.start.to_i128(), value.end.to_i128());
+        let len = end - start;
+
+        let shorter = shrink_towards(len, 0)
+            .into_iter()
+            .map(|len| T::from_i128(start)..T::from_i128(start + len));
+        let moved = shrink_towards(start, self.lo.to_i128())
+            .into_iter()
+            .map(|start| T::from_i128(start)..T::from_i128(start + len));
+
+        shorter.chain(moved).collect()
+    }
+}
+
+/// Rectangular grids, shrinking by removing rows and columns and then simplifying cells.
+#[derive(Debug, Clone)]
+pub struct Grids<S> {
+    cell: S,
+    rows: RangeInclusive<usize>,
+    cols: RangeInclusive<usize>,
+}
+
+/// Creates grids of values from `cell`, indexed as `grid[row][col]`.
+pub fn grid<S: Strategy>(
+    cell: S,
+    rows: RangeInclusive<usize>,
+    cols: RangeInclusive<usize>,
+) -> Grids<S> {
+    assert!(
+        !rows.is_empty() && !cols.is_empty(),
+        "cannot generate grids from an empty range"
+    );
+    Grids { cell, rows, cols }
+}
+
+impl<S: Strategy> Strategy for Grids<S> {
+    type Value = Vec<Vec<S::Value>>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        #[allow(clippy::cast_possible_truncation)]
+        let rows = int_in(rng, *self.rows.start() as i128, *self.rows.end() as i128) as usize;
+        #[allow(clippy::cast_possible_truncation)]
+        let cols = int_in(rng, *self.cols.start() as i128, *self.cols.end() as i128) as usize;
+
+        (0..rows)
+            .map(|_| (0..cols).map(|_| self.cell.generate(rng)).collect())
+            .collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let mut candidates = vec![];
+        let cols = value.first().map_or(0, Vec::len);
+
+        if value.len() > *self.rows.start() {
+            for i in 0..value.len() {
+                let mut candidate = value.clone();
+                candidate.remove(i);
+                candidates.push(candidate);
+            }
+        }
+
+        if cols > *self.cols.start() {
+            for j in 0..cols {
+                let mut candidate = value.clone();
+                for row in &mut candidate {
+                    row.remove(j);
+                }
+                candidates.push(candidate);
+            }
+        }
+
+        for (i, row) in value.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                for shrunk in self.cell.shrink(cell) {
+                    let mut candidate = value.clone();
+                    candidate[i][j] = shrunk;
+                    candidates.push(candidate);
+                }
+            }
+        }
+
+        candidates
+    }
+}
+
+impl<A: Strategy, B: Strategy> Strategy for (A, B) {
+    type Value = (A::Value, B::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        (self.0.generate(rng), self.1.generate(rng))
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let first = self
+            .0
+            .shrink(&value.0)
+            .into_iter()
+            .map(|a| (a, value.1.clone()));
+        let second = self
+            .1
+            .shrink(&value.1)
+            .into_iter()
+            .map(|b| (value.0.clone(), b));
+        first.chain(second).collect()
+    }
+}
+
+impl<A: Strategy, B: Strategy, C: Strategy> Strategy for (A, B, C) {
+    type Value = (A::Value, B::Value, C::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        (
+            self.0.generate(rng),
+            self.1.generate(rng),
+            self.2.generate(rng),
+        )
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let (a, b, c) = value;
+        let first = self
+            .0
+            .shrink(a)
+            .into_iter()
+            .map(|x| (x, b.clone(), c.clone()));
+        let second = self
+            .1
+            .shrink(b)
+            .into_iter()
+            .map(|x| (a.clone(), x, c.clone()));
+        let third = self
+            .2
+            .shrink(c)
+            .into_iter()
+            .map(|x| (a.clone(), b.clone(), x));
+        first.chain(second).chain(third).collect()
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(all(test, feature = "test_lib"))]
+mod tests {
+    use super::{check, grid, int, one_of, range, vec, Config, Strategy};
+    use std::panic;
+
+    fn failure_message(f: impl FnOnce() + panic::UnwindSafe) -> String {
+        let err = panic::catch_unwind(f).unwrap_err();
+        err.downcast_ref::<String>().unwrap().clone()
+    }
+
+    #[test]
+    fn passing_property() {
+        check(&int(-10..=10_i32), |x| x.abs() <= 10);
+        check(&range(0..=100_usize, 10), |r| r.end <= 100 && r.len() <= 10);
+    }
+
+    #[test]
+    fn shrinks_integers() {
+        let message = failure_message(|| check(&int(0..=1000_u32), |x| *x < 50));
+        assert!(message.contains("minimal input"));
+        assert!(message.contains("shrinks): 50\n"), "{message}");
+    }
+
+    #[test]
+    fn shrinks_vectors() {
+        let message = failure_message(|| {
+            check(&vec(int(0..=100_i64), 0..=20), |v| {
+                v.iter().all(|x| *x < 10)
+            })
+        });
+        assert!(message.contains("shrinks): [10]\n"), "{message}");
+    }
+
+    #[test]
+    fn shrinks_grids() {
+        let message = failure_message(|| {
+            check(&grid(one_of(&['.', '#']), 1..=5, 1..=5), |g| {
+                g.iter().flatten().all(|c| *c == '.')
+            })
+        });
+        assert!(message.contains("shrinks): [['#']]\n"), "{message}");
+    }
+
+    #[test]
+    fn is_deterministic() {
+        let strategy = vec(int(0..=u64::MAX), 5..=5);
+        let config = Config::default();
+        let mut a = super::Rng::new(config.seed);
+        let mut b = super::Rng::new(config.seed);
+        assert_eq!(strategy.generate(&mut a), strategy.generate(&mut b));
+    }
+}